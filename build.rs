@@ -0,0 +1,82 @@
+//! Checks the emoji dataset in `src/mojis.rs` before anything is built from it, so a bad
+//! entry fails the build pointing at its line rather than showing up as a broken tile.
+
+use std::{collections::HashMap, fs};
+
+const DATASET: &str = "src/mojis.rs";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", DATASET);
+    let source = fs::read_to_string(DATASET).expect("src/mojis.rs is readable");
+
+    let categories = categories(&source);
+    let mut errors = Vec::new();
+    let mut names = HashMap::new();
+    let entries = source
+        .lines()
+        .enumerate()
+        .skip_while(|(_, line)| !line.starts_with("pub static EMOJIS"))
+        .skip(1)
+        .take_while(|(_, line)| *line != "];");
+    for (idx, line) in entries {
+        let line_no = idx + 1;
+        let (name, glyph, category) = match entry(line) {
+            Some(entry) => entry,
+            None => {
+                errors.push(format!(
+                    "{}:{}: unreadable entry `{}`",
+                    DATASET, line_no, line
+                ));
+                continue;
+            }
+        };
+        if let Some(first) = names.insert(name, line_no) {
+            errors.push(format!(
+                "{}:{}: `{}` is described again, first at line {}",
+                DATASET, line_no, name, first
+            ));
+        }
+        if glyph.trim().is_empty() {
+            errors.push(format!("{}:{}: `{}` has no glyph", DATASET, line_no, name));
+        }
+        if !categories.contains(&category) {
+            errors.push(format!(
+                "{}:{}: `{}` is in `{}`, which is not a Category",
+                DATASET, line_no, name, category
+            ));
+        }
+    }
+    if names.is_empty() {
+        errors.push(format!("{}: no entries found in EMOJIS", DATASET));
+    }
+    if !errors.is_empty() {
+        panic!("the emoji dataset is broken\n{}", errors.join("\n"));
+    }
+}
+
+/// Splits `    emoji("name", "glyph", Category),` into its parts.
+fn entry(line: &str) -> Option<(&str, &str, &str)> {
+    let args = line.trim().strip_prefix("emoji(")?.strip_suffix("),")?;
+    let (name, rest) = quoted(args)?;
+    let (glyph, rest) = quoted(rest.strip_prefix(',')?.trim_start())?;
+    let category = rest.strip_prefix(',')?.trim();
+    Some((name, glyph, category))
+}
+
+/// The string literal `text` starts with and what follows it.
+fn quoted(text: &str) -> Option<(&str, &str)> {
+    let text = text.strip_prefix('"')?;
+    let end = text.find('"')?;
+    Some((&text[..end], &text[end + 1..]))
+}
+
+/// The variants of `enum Category`.
+fn categories(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .skip_while(|line| !line.starts_with("pub enum Category"))
+        .skip(1)
+        .take_while(|line| *line != "}")
+        .filter_map(|line| line.trim().strip_suffix(','))
+        .collect()
+}
@@ -0,0 +1,64 @@
+use std::env;
+
+pub const USAGE: &str = "\
+Usage: emojay [options]
+
+With no options the picker is opened.
+
+Options:
+  --search <query> --print  Print the emoji that best matches <query>
+  --copy <query>            Copy the emoji that best matches <query>
+  --tty                     Type the picked emoji at the shell prompt instead of
+                            copying it, where the terminal allows it
+  --reset-stats             Forget how often each emoji was copied
+  --help                    Show this message
+  --version                 Show the version of emojay";
+
+/// What was asked for on the command line, for using the picker from scripts.
+#[derive(Debug, Default)]
+pub struct Args {
+    /// The description to fuzzy match against instead of typing it in the picker.
+    pub search: Option<String>,
+    /// Print the best match for `search` to stdout rather than opening the picker.
+    pub print: bool,
+    /// Copy the best match for this description rather than opening the picker.
+    pub copy: Option<String>,
+    /// Type the picked emoji into the terminal the picker was started from.
+    pub tty: bool,
+    /// Forget how often each emoji was copied.
+    pub reset_stats: bool,
+    /// Print the usage instead of doing anything else.
+    pub help: bool,
+    /// Print the version instead of doing anything else.
+    pub version: bool,
+}
+
+impl Args {
+    pub fn parse() -> Result<Self, String> {
+        let mut args = Args::default();
+        let mut argv = env::args().skip(1);
+        while let Some(arg) = argv.next() {
+            match arg.as_str() {
+                "--search" => {
+                    args.search = Some(argv.next().ok_or("`--search` needs a query")?);
+                }
+                "--print" => args.print = true,
+                "--copy" => {
+                    args.copy = Some(argv.next().ok_or("`--copy` needs a query")?);
+                }
+                "--tty" => args.tty = true,
+                "--reset-stats" => args.reset_stats = true,
+                "--help" | "-h" => args.help = true,
+                "--version" | "-V" => args.version = true,
+                other => return Err(format!("unknown argument `{}`", other)),
+            }
+        }
+        if args.help || args.version {
+            return Ok(args);
+        }
+        if args.search.is_some() != args.print {
+            return Err("`--search <query>` and `--print` go together".into());
+        }
+        Ok(args)
+    }
+}
@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "druid")]
+use druid::Data;
+use serde::{Deserialize, Serialize};
+
+use self::OutputFormat::*;
+use crate::{mojis, Emoji, SkinTone};
+
+/// What copying an emoji puts on the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "druid", derive(Data))]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// The emoji itself.
+    Glyph,
+    /// Its `:shortcode:`, as chat apps know it.
+    Shortcode,
+    /// HTML numeric character references like `&#128512;`.
+    HtmlEntity,
+    /// Rust style escapes like `\u{1F600}`.
+    UnicodeEscape,
+    /// The codepoints spelled out like `U+1F469 U+200D U+1F4BB`, for bug reports and
+    /// docs.
+    Codepoints,
+}
+
+impl OutputFormat {
+    pub const ALL: &'static [OutputFormat] =
+        &[Glyph, Shortcode, HtmlEntity, UnicodeEscape, Codepoints];
+
+    /// How the format is shown in the picker.
+    pub fn label(self) -> &'static str {
+        match self {
+            Glyph => "emoji",
+            Shortcode => ":shortcode:",
+            HtmlEntity => "&#entity;",
+            UnicodeEscape => "\\u{escape}",
+            Codepoints => "U+codepoints",
+        }
+    }
+
+    /// The format after this one, wrapping around to the first.
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|&f| f == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    /// Writes out `emoji` with `tone` applied if it takes one. Every codepoint of a
+    /// sequence is written out, variation selectors and joiners included.
+    pub fn apply(self, emoji: Emoji, tone: SkinTone) -> Cow<'static, str> {
+        let glyph = mojis::with_skin_tone(emoji.glyph, tone);
+        match self {
+            Glyph => glyph,
+            Shortcode => format!(":{}:", mojis::shortcode(emoji.name)).into(),
+            HtmlEntity => glyph.chars().map(|c| format!("&#{};", c as u32)).collect(),
+            UnicodeEscape => {
+                glyph.chars().map(|c| format!("\\u{{{:X}}}", c as u32)).collect()
+            }
+            Codepoints => glyph
+                .chars()
+                .map(|c| format!("U+{:04X}", c as u32))
+                .collect::<Vec<_>>()
+                .join(" ")
+                .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_emoji;
+
+    #[test]
+    fn sequences_are_copied_whole() {
+        for (name, len) in [
+            ("woman_technologist", 11),
+            ("family_man_woman_girl_boy", 25),
+            ("rainbow_flag", 14),
+            ("curacao", 8),
+            ("heart", 6),
+        ] {
+            let emoji = find_emoji(name).unwrap();
+            assert_eq!(emoji.glyph.len(), len, "{}", name);
+            assert_eq!(Glyph.apply(emoji, SkinTone::Default), emoji.glyph, "{}", name);
+        }
+    }
+
+    #[test]
+    fn every_person_of_a_sequence_is_toned() {
+        let toned = |name| {
+            let emoji = find_emoji(name).unwrap();
+            Glyph.apply(emoji, SkinTone::Medium).into_owned()
+        };
+        assert_eq!(toned("woman_technologist"), "👩🏽\u{200D}💻");
+        assert_eq!(toned("basketball_woman"), "⛹🏽\u{200D}♀️");
+        assert_eq!(toned("couple_with_heart_woman_woman"), "👩🏽\u{200D}❤️\u{200D}👩🏽");
+        assert_eq!(toned("handshake"), "🤝🏽");
+        assert_eq!(mojis::with_skin_tone("🧑‍🤝‍🧑", SkinTone::Medium), "🧑🏽‍🤝‍🧑🏽");
+        let family = find_emoji("family_man_woman_girl_boy").unwrap();
+        assert_eq!(Glyph.apply(family, SkinTone::Medium), family.glyph);
+    }
+}
@@ -0,0 +1,43 @@
+use std::{env, thread};
+
+use druid::{ExtEventSink, Target};
+use global_hotkey::{hotkey::HotKey, GlobalHotKeyEvent, GlobalHotKeyManager};
+
+use crate::SUMMON;
+
+/// The shortcut used when `EMOJAY_HOTKEY` isn't set.
+const DEFAULT_HOTKEY: &str = "ctrl+alt+KeyE";
+
+/// A global shortcut that summons the picker, registered for as long as this is alive.
+pub struct Hotkey {
+    hotkey: HotKey,
+    _manager: GlobalHotKeyManager,
+}
+
+impl Hotkey {
+    /// Registers the shortcut named by `EMOJAY_HOTKEY`, e.g. `ctrl+shift+Semicolon`.
+    pub fn register() -> Result<Self, String> {
+        let binding = env::var("EMOJAY_HOTKEY").unwrap_or_else(|_| DEFAULT_HOTKEY.into());
+        let hotkey = binding
+            .parse::<HotKey>()
+            .map_err(|err| format!("`{}` isn't a shortcut: {}", binding, err))?;
+        let manager = GlobalHotKeyManager::new().map_err(|err| err.to_string())?;
+        manager.register(hotkey).map_err(|err| err.to_string())?;
+        Ok(Hotkey { hotkey, _manager: manager })
+    }
+
+    /// Sends `SUMMON` to the picker whenever the shortcut is pressed.
+    pub fn forward(&self, sink: ExtEventSink) {
+        let id = self.hotkey.id();
+        thread::spawn(move || {
+            for event in GlobalHotKeyEvent::receiver() {
+                if event.id == id
+                    && sink.submit_command(SUMMON, (), Target::Auto).is_err()
+                {
+                    // The app has quit.
+                    break;
+                }
+            }
+        });
+    }
+}
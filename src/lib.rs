@@ -0,0 +1,523 @@
+//! The emoji data and the search behind the picker, usable without the GUI. Depending on
+//! the crate with `default-features = false` leaves druid and the rest of the picker out,
+//! the `druid` feature brings back the `Data` impls of the types here.
+//!
+//! ```no_run
+//! for emoji in emojay::search("smile") {
+//!     println!("{} {}", emoji.glyph, emoji.name);
+//! }
+//! ```
+//!
+//! `filter_emojis` is the same search over a set of emojis of your own, with no state
+//! of the picker involved, which is what tests of the matching should go through. The
+//! `#[doc(hidden)]` helpers are only public for the picker and can change at any time.
+
+use std::{borrow::Cow, cmp::Reverse, collections::HashMap, iter, sync::OnceLock};
+
+#[cfg(feature = "druid")]
+use druid::Data;
+use fuzzy_matcher as fz;
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+pub mod format;
+pub mod locale;
+pub mod mojis;
+
+pub use format::OutputFormat;
+pub use locale::Language;
+pub use mojis::{Category, Emoji, SkinTone, EMOJIS};
+
+/// The fuzzy match score an emoji has to beat to count as a match.
+pub const DEFAULT_THRESHOLD: i64 = 25;
+
+/// Words of descriptions that say little about the emoji, like the "face" of
+/// "slightly_smiling_face".
+const FILLER: &[&str] = &["face", "with", "and", "of", "the", "a", "on", "in"];
+
+/// The fuzzy matching algorithms a search can be scored with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "druid", derive(Data))]
+#[serde(rename_all = "lowercase")]
+pub enum Matcher {
+    /// skim's take on fzf's algorithm, it favors hits at the start of words so it reads
+    /// descriptions the way people type them.
+    #[default]
+    Skim,
+    /// The matcher clangd completes code with.
+    Clangd,
+}
+
+/// How the words of emojis are matched against a search, past the threshold.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Matching {
+    pub matcher: Matcher,
+    /// Forgive a slip or two where fuzzy matching finds nothing, like "recylce" for
+    /// "recycle".
+    pub typos: bool,
+    /// Also match the words of this language.
+    pub language: Language,
+}
+
+impl Matcher {
+    fn get(self) -> Box<dyn fz::FuzzyMatcher> {
+        match self {
+            Matcher::Skim => Box::new(fz::skim::SkimMatcherV2::default()),
+            Matcher::Clangd => Box::new(fz::clangd::ClangdMatcher::default()),
+        }
+    }
+}
+
+/// Every emoji matching `query`, best matches first.
+pub fn search(query: &str) -> Vec<Emoji> {
+    filter_emojis(EMOJIS, query, DEFAULT_THRESHOLD)
+}
+
+/// The emojis of `all` that match `query` with a fuzzy score above `threshold`, best
+/// matches first.
+pub fn filter_emojis(all: &[Emoji], query: &str, threshold: i64) -> Vec<Emoji> {
+    ranked(all.iter().copied(), query, None, threshold)
+}
+
+/// The emoji described exactly by `name`.
+pub fn find_emoji(name: &str) -> Option<Emoji> {
+    EMOJIS.iter().find(|e| e.name == name).copied()
+}
+
+/// The description of the emoji `glyph` is, sequences included, with or without its
+/// variation selectors.
+///
+/// ```
+/// assert_eq!(emojay::describe("🚀"), Some("rocket"));
+/// ```
+pub fn describe(glyph: &str) -> Option<&'static str> {
+    static INDEX: OnceLock<HashMap<String, &'static str>> = OnceLock::new();
+    let bare = |glyph: &str| glyph.replace('\u{FE0F}', "");
+    INDEX
+        .get_or_init(|| EMOJIS.iter().map(|e| (bare(e.glyph), e.name)).collect())
+        .get(&bare(glyph.trim()))
+        .copied()
+}
+
+/// The emojis of `source` that match `search`, best matches first.
+fn ranked(
+    source: impl Iterator<Item = Emoji>,
+    search: &str,
+    category: Option<Category>,
+    threshold: i64,
+) -> Vec<Emoji> {
+    ranked_by_use(source, search, category, threshold, Matching::default(), |_| 0)
+}
+
+/// Like `filter_emojis`, but matching as `matching` says, and of the emojis that match
+/// equally well the ones `uses` counts more uses of come first.
+#[doc(hidden)]
+pub fn ranked_by_use(
+    source: impl Iterator<Item = Emoji>,
+    search: &str,
+    category: Option<Category>,
+    threshold: i64,
+    matching: Matching,
+    uses: impl Fn(&Emoji) -> u32,
+) -> Vec<Emoji> {
+    let Matching { matcher, typos, language } = matching;
+    let in_category = |e: &Emoji| category.is_none_or(|cat| e.group == cat);
+    // With nothing typed everything matches, this is what the picker opens with so it
+    // skips the matcher entirely and keeps the dataset's order.
+    if search.is_empty() {
+        return source.filter(in_category).collect();
+    }
+    let codepoint = codepoint_query(search);
+    let prefixed = search.get(..2).is_some_and(|start| start.eq_ignore_ascii_case("u+"));
+    let search = &*fold(search);
+    // Naming a subgroup lists just that subgroup, in the dataset's order, and naming a
+    // group lists its category.
+    if mojis::is_subgroup(search) {
+        return source
+            .filter(in_category)
+            .filter(|e| mojis::in_subgroup(e.subgroup(), search))
+            .collect();
+    }
+    if let Some(group) = Category::named(search) {
+        return source.filter(in_category).filter(|e| e.group == group).collect();
+    }
+
+    let matcher = matcher.get();
+    let mut scored = source
+        .filter(in_category)
+        .filter_map(|e| {
+            if let Some(codepoint) = codepoint {
+                let mut chars = e.glyph.chars().filter(|&c| c != '\u{FE0F}');
+                match codepoint {
+                    Some(c) if chars.clone().eq(iter::once(c)) => {
+                        return Some((i64::MAX, e))
+                    }
+                    Some(c) if chars.any(|g| g == c) => return Some((i64::MAX - 1, e)),
+                    // Bare hex like "1234" could as well be a description, the one of
+                    // 🔢 here, so it's searched as text too.
+                    _ if !prefixed => {}
+                    _ => return None,
+                }
+            }
+            let words = words(&e, language);
+            // Exact hits always beat fuzzy ones.
+            if let Some(score) =
+                words.clone().filter_map(|word| literal_score(&word, search)).max()
+            {
+                Some((score, e))
+            } else {
+                let fuzzy =
+                    words.clone().filter_map(|word| matcher.fuzzy_match(&word, search));
+                let typo = words
+                    .filter(|_| typos)
+                    .filter_map(|word| typo_score(&word, search, threshold));
+                fuzzy
+                    .chain(typo)
+                    .max()
+                    .filter(|&score| score > threshold)
+                    .map(|score| (score, e))
+            }
+        })
+        .collect::<Vec<_>>();
+    scored.sort_by_cached_key(|(score, e)| {
+        (Reverse(*score), Reverse(uses(e)), without_filler(e.name).len())
+    });
+    scored.into_iter().map(|(_, e)| e).collect()
+}
+
+/// Whether `search` appears as is in any of the words of `emoji` rather than only
+/// fuzzily, is one of its codepoints, or names its subgroup or group. These sort ahead of
+/// everything else `ranked_by_use` finds.
+#[doc(hidden)]
+pub fn is_literal_match(emoji: &Emoji, search: &str, language: Language) -> bool {
+    if let Some(Some(codepoint)) = codepoint_query(search) {
+        if emoji.glyph.contains(codepoint) {
+            return true;
+        }
+    }
+    let search = &*fold(search);
+    !search.is_empty()
+        && (mojis::is_subgroup(search) && mojis::in_subgroup(emoji.subgroup(), search)
+            || Category::named(search) == Some(emoji.group)
+            || words(emoji, language).any(|word| word.contains(search)))
+}
+
+/// Everything an emoji is searched by, folded. The description is there with its filler
+/// words as well as without, so a short search scores on the words that matter.
+fn words(
+    emoji: &Emoji,
+    language: Language,
+) -> impl Iterator<Item = Cow<'static, str>> + Clone {
+    let essential = Some(without_filler(emoji.name)).filter(|name| name != emoji.name);
+    iter::once(emoji.name)
+        .chain(emoji.keywords.iter().copied())
+        .chain(emoji.group.keyword())
+        .chain(language.keywords(emoji.name).iter().copied())
+        .map(fold)
+        .chain(essential.map(Cow::Owned))
+}
+
+/// `name` without its `FILLER` words, unless that leaves nothing.
+fn without_filler(name: &str) -> String {
+    let words =
+        name.split(['_', ' ']).filter(|word| !FILLER.contains(word)).collect::<Vec<_>>();
+    if words.is_empty() {
+        name.to_owned()
+    } else {
+        words.join("_")
+    }
+}
+
+/// Scores `search` appearing as is in `word`. A whole word of it beats the start of one,
+/// which beats a hit in the middle, so "cat" puts the cat well above "certificate".
+fn literal_score(word: &str, search: &str) -> Option<i64> {
+    let boundary = |c: Option<char>| c.is_none_or(|c| matches!(c, '_' | ' ' | '-'));
+    word.match_indices(search)
+        .map(|(start, hit)| {
+            let before = word[..start].chars().next_back();
+            let after = word[start + hit.len()..].chars().next();
+            match (boundary(before), boundary(after)) {
+                (true, true) => i64::MAX,
+                (true, false) => i64::MAX - 1,
+                _ => i64::MAX - 2,
+            }
+        })
+        .max()
+}
+
+/// Scores `search` as a misspelling of `word` or one of its words, allowing an edit for
+/// every four characters typed. Any hit beats `threshold`, fewer edits score higher.
+fn typo_score(word: &str, search: &str, threshold: i64) -> Option<i64> {
+    let len = search.chars().count();
+    let allowed = len / 4;
+    if allowed == 0 {
+        return None;
+    }
+    iter::once(word)
+        .chain(word.split(['_', ' ', '-']))
+        .map(|part| edit_distance(part, search))
+        .min()
+        .filter(|&edits| edits <= allowed)
+        .map(|edits| threshold + 1 + ((len - edits) * 16 / (edits + 1)) as i64)
+}
+
+/// How many characters have to be inserted, removed, replaced or swapped with their
+/// neighbor to turn `a` into `b`, the optimal string alignment distance. A swap counts
+/// once, so "recylce" is one edit from "recycle".
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.chars().collect::<Vec<_>>(), b.chars().collect::<Vec<_>>());
+    // Three rows of the table are enough, the one before last is for swaps.
+    let mut before = vec![0; b.len() + 1];
+    let mut last = (0..=b.len()).collect::<Vec<_>>();
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (last[j] + 1).min(row[j - 1] + 1).min(last[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(before[j - 2] + 1);
+            }
+        }
+        before = std::mem::replace(&mut last, row);
+    }
+    last[b.len()]
+}
+
+/// Drops the accents and capitals from `text` so "Cafe" and "café" match each other.
+#[doc(hidden)]
+pub fn fold(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return if text.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(text.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(text)
+        };
+    }
+    Cow::Owned(
+        text.nfd()
+            .filter(|&c| !is_combining_mark(c))
+            .flat_map(char::to_lowercase)
+            .collect(),
+    )
+}
+
+/// Where the characters of `name` that `search` matched are.
+#[doc(hidden)]
+pub fn matched_indices(name: &str, search: &str, matcher: Matcher) -> Vec<usize> {
+    let search = &*fold(search);
+    match name.find(search) {
+        Some(start) if !search.is_empty() => (start..start + search.len()).collect(),
+        _ => matcher
+            .get()
+            .fuzzy_indices(name, search)
+            .map(|(_, indices)| indices)
+            .unwrap_or_default(),
+    }
+}
+
+/// Reads a `U+1F600` or bare `1f600` style search, `Some(None)` when it looks like a
+/// codepoint but isn't a valid one. Bare hex needs a digit and four characters so words
+/// like "face" are still searched as text, and whatever bare hex doesn't find as a
+/// codepoint is searched as text as well.
+#[doc(hidden)]
+pub fn codepoint_query(search: &str) -> Option<Option<char>> {
+    let hex = match search.strip_prefix("U+").or_else(|| search.strip_prefix("u+")) {
+        Some(hex) => hex,
+        None if search.len() >= 4
+            && search.chars().all(|c| c.is_ascii_hexdigit())
+            && search.chars().any(|c| c.is_ascii_digit()) =>
+        {
+            search
+        }
+        None => return None,
+    };
+    Some(u32::from_str_radix(hex, 16).ok().and_then(char::from_u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(emojis: &[Emoji]) -> Vec<&'static str> {
+        emojis.iter().map(|e| e.name).collect()
+    }
+
+    #[test]
+    fn substring_hits_are_found() {
+        let found = names(&search("rocket"));
+        assert_eq!(found.first(), Some(&"rocket"));
+    }
+
+    #[test]
+    fn fuzzy_hits_need_to_beat_the_threshold() {
+        let rocket = [find_emoji("rocket").unwrap()];
+        // "rckt" is nowhere in the words of the rocket as is, only fuzzily.
+        let score = words(&rocket[0], Language::English)
+            .filter_map(|word| Matcher::Skim.get().fuzzy_match(&word, "rckt"))
+            .max()
+            .unwrap();
+        assert!(score > DEFAULT_THRESHOLD, "rckt scores {}", score);
+        assert_eq!(names(&filter_emojis(&rocket, "rckt", score - 1)), ["rocket"]);
+        assert!(filter_emojis(&rocket, "rckt", score).is_empty());
+    }
+
+    #[test]
+    fn empty_query_keeps_everything_in_order() {
+        assert_eq!(names(&filter_emojis(EMOJIS, "", DEFAULT_THRESHOLD)), names(EMOJIS));
+    }
+
+    #[test]
+    fn empty_query_skips_the_matcher() {
+        // Only scored emojis are asked for their uses, so this shows nothing was scored
+        // for the list the picker opens with.
+        let unscored = |_: &Emoji| -> u32 { panic!("the empty search was scored") };
+        let rank = |category| {
+            let source = EMOJIS.iter().copied();
+            let threshold = DEFAULT_THRESHOLD;
+            ranked_by_use(source, "", category, threshold, Matching::default(), unscored)
+        };
+        assert_eq!(rank(None).len(), EMOJIS.len());
+        let travel = rank(Some(Category::Travel));
+        assert!(!travel.is_empty() && travel.iter().all(|e| e.group == Category::Travel));
+    }
+
+    #[test]
+    fn codepoints_are_found() {
+        assert_eq!(search("U+1F680").first().map(|e| e.name), Some("rocket"));
+        assert_eq!(search("1f680").first().map(|e| e.name), Some("rocket"));
+        assert!(search("U+ZZZ").is_empty());
+    }
+
+    #[test]
+    fn hex_looking_descriptions_are_found() {
+        assert_eq!(search("1234").first().map(|e| e.name), Some("1234"));
+        // With the prefix it's only ever a codepoint, and U+1234 is no emoji.
+        assert!(search("U+1234").is_empty());
+    }
+
+    #[test]
+    fn best_and_shortest_matches_come_first() {
+        let found = names(&search("cat"));
+        let at = |name| found.iter().position(|&n| n == name).unwrap();
+        assert_eq!(found[0], "cat");
+        assert!(at("cat") < at("joy_cat"));
+        // A whole word beats the start of one however short.
+        assert!(at("crying_cat_face") < at("cat2"));
+    }
+
+    #[test]
+    fn subgroups_and_groups_are_listed() {
+        let smiling = search("face-smiling");
+        assert!(smiling.iter().all(|e| e.subgroup() == "face-smiling"));
+        assert_eq!(smiling.first().map(|e| e.name), Some("grinning"));
+        let fingers = search("hand-fingers");
+        assert!(fingers.iter().any(|e| e.subgroup() == "hand-fingers-open"));
+        assert!(fingers.iter().any(|e| e.subgroup() == "hand-fingers-closed"));
+        assert!(fingers.iter().all(|e| e.subgroup().starts_with("hand-fingers-")));
+        assert!(search("transport-air").iter().any(|e| e.name == "rocket"));
+        assert!(search("country-flag").iter().any(|e| e.name == "curacao"));
+
+        let animals = search("animals & nature");
+        assert_eq!(
+            animals.len(),
+            EMOJIS.iter().filter(|e| e.group == Category::Animals).count()
+        );
+        // Single words are still searched as text.
+        assert_eq!(search("heart").first().map(|e| e.name), Some("heart"));
+        assert!(search("flag").iter().any(|e| e.name == "curacao"));
+    }
+
+    #[test]
+    fn every_emoji_has_a_subgroup() {
+        let without = EMOJIS.iter().filter(|e| e.subgroup().is_empty()).map(|e| e.name);
+        assert_eq!(without.collect::<Vec<_>>(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn accents_dont_matter() {
+        assert_eq!(fold("Café"), "cafe");
+        assert_eq!(search("cafe").first().map(|e| e.glyph), Some("☕"));
+        assert_eq!(search("café").first().map(|e| e.glyph), Some("☕"));
+        assert_eq!(search("curaçao").first().map(|e| e.glyph), Some("🇨🇼"));
+        assert_eq!(search("curacao").first().map(|e| e.glyph), Some("🇨🇼"));
+    }
+
+    #[test]
+    fn capitals_dont_matter() {
+        assert_eq!(names(&search("GRINNING")), names(&search("grinning")));
+        assert_eq!(search("GRINNING").first().map(|e| e.name), Some("grinning"));
+        assert_eq!(names(&search("Cat")), names(&search("cat")));
+    }
+
+    #[test]
+    fn either_matcher_finds_abbreviations() {
+        for matcher in [Matcher::Skim, Matcher::Clangd] {
+            let matching = Matching { matcher, ..Default::default() };
+            let found = ranked_by_use(
+                EMOJIS.iter().copied(),
+                "rckt",
+                None,
+                DEFAULT_THRESHOLD,
+                matching,
+                |_| 0,
+            );
+            assert_eq!(found.first().map(|e| e.name), Some("rocket"), "{:?}", matcher);
+        }
+        // The default threshold lets skim's abbreviations through, but not a lone letter
+        // from the middle of a word.
+        let skim = Matcher::Skim.get();
+        assert!(skim.fuzzy_match("rocket", "rckt").unwrap() > DEFAULT_THRESHOLD);
+        assert!(skim.fuzzy_match("rocket", "c").unwrap() <= DEFAULT_THRESHOLD);
+    }
+
+    #[test]
+    fn glyphs_are_described() {
+        assert_eq!(describe("👩‍💻"), Some("woman_technologist"));
+        assert_eq!(describe("👨‍👩‍👧‍👦"), Some("family_man_woman_girl_boy"));
+        assert_eq!(describe("🇨🇼"), Some("curacao"));
+        // Pasted text doesn't always keep its variation selectors.
+        assert_eq!(describe("\u{2764}\u{FE0F}"), Some("heart"));
+        assert_eq!(describe("\u{2764}"), Some("heart"));
+        assert_eq!(describe("🏳‍🌈"), Some("rainbow_flag"));
+        assert_eq!(describe("rocket"), None);
+    }
+
+    #[test]
+    fn filler_words_dont_count() {
+        let found = names(&search("grin"));
+        let grinning = found.iter().position(|&n| n == "grinning").unwrap();
+        let filler = |name: &str| name.split('_').any(|word| FILLER.contains(&word));
+        assert!(found[..grinning].iter().all(|name| !filler(name)), "{:?}", found);
+        // Ties go to the shorter description, less its "face".
+        assert_eq!(without_filler("crying_cat_face"), "crying_cat");
+        let found = names(&search("cat"));
+        let at = |name| found.iter().position(|&n| n == name).unwrap();
+        assert!(at("crying_cat_face") < at("pouting_cat"));
+    }
+
+    #[test]
+    fn typos_are_forgiven_when_asked_to() {
+        let typos = Matching { typos: true, ..Default::default() };
+        let find = |query| {
+            ranked_by_use(
+                EMOJIS.iter().copied(),
+                query,
+                None,
+                DEFAULT_THRESHOLD,
+                typos,
+                |_| 0,
+            )
+            .first()
+            .map(|e| e.name)
+        };
+        assert_eq!(find("recylce"), Some("recycle"));
+        assert_eq!(find("rocekt"), Some("rocket"));
+        assert!(search("recylce").iter().all(|e| e.name != "recycle"));
+    }
+
+    #[test]
+    fn nothing_matches_gibberish() {
+        assert!(search("qqxzjv").is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(feature = "druid")]
+use druid::Data;
+use serde::{Deserialize, Serialize};
+
+/// The languages emojis can be searched in besides English.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "druid", derive(Data))]
+pub enum Language {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "de")]
+    German,
+}
+
+impl Language {
+    /// The words the emoji described by `name` is found by in this language, on top of
+    /// its English ones.
+    pub fn keywords(self, name: &str) -> &'static [&'static str] {
+        let table = match self {
+            Language::English => return &[],
+            Language::German => GERMAN,
+        };
+        table.iter().find(|(n, _)| *n == name).map_or(&[], |(_, words)| words)
+    }
+}
+
+/// German words for the emojis people reach for most, after the CLDR annotations.
+static GERMAN: &[(&str, &[&str])] = &[
+    ("grinning", &["grinsendes gesicht", "grinsen"]),
+    ("grin", &["strahlendes gesicht", "grinsen"]),
+    ("joy", &["freudentränen", "lachen"]),
+    ("rofl", &["vor lachen auf dem boden wälzen", "lachen"]),
+    ("smiley", &["grinsendes gesicht mit großen augen", "lächeln"]),
+    ("smile", &["lächeln", "fröhlich"]),
+    ("sweat_smile", &["grinsen mit schweiß"]),
+    ("laughing", &["lachen", "zusammengekniffene augen"]),
+    ("innocent", &["unschuldig", "heiligenschein"]),
+    ("wink", &["zwinkern"]),
+    ("blush", &["lächeln", "errötet"]),
+    ("slightly_smiling_face", &["leicht lächelndes gesicht"]),
+    ("upside_down_face", &["umgekehrtes gesicht"]),
+    ("yum", &["lecker"]),
+    ("heart_eyes", &["herzaugen", "verliebt"]),
+    ("kissing_heart", &["kuss", "kusshand"]),
+    ("stuck_out_tongue", &["zunge"]),
+    ("nerd_face", &["streber"]),
+    ("sunglasses", &["sonnenbrille", "cool"]),
+    ("clown_face", &["clown"]),
+    ("hugs", &["umarmung"]),
+    ("smirk", &["grinsen", "selbstgefällig"]),
+    ("neutral_face", &["neutrales gesicht"]),
+    ("unamused", &["genervt"]),
+    ("roll_eyes", &["augenrollen"]),
+    ("thinking", &["nachdenken", "denken"]),
+    ("lying_face", &["lügen", "pinocchio"]),
+    ("shushing", &["psst", "leise"]),
+    ("exploding_head", &["explodierender kopf"]),
+    ("flushed", &["errötet", "verlegen"]),
+    ("disappointed", &["enttäuscht"]),
+    ("worried", &["besorgt"]),
+    ("angry", &["verärgert", "wütend"]),
+    ("rage", &["wütend", "zornig"]),
+    ("pensive", &["nachdenklich"]),
+    ("confused", &["verwirrt"]),
+    ("tired_face", &["müde"]),
+    ("weary", &["erschöpft"]),
+    ("pleading", &["flehend", "bitte"]),
+    ("scream", &["schreien", "angst"]),
+    ("fearful", &["ängstlich"]),
+    ("cry", &["weinen", "träne"]),
+    ("sob", &["heulen", "weinen"]),
+    ("sleepy", &["schläfrig"]),
+    ("sleeping", &["schlafen"]),
+    ("mask", &["maske", "krank"]),
+    ("nauseated_face", &["übelkeit"]),
+    ("vomiting", &["erbrechen"]),
+    ("skull", &["totenkopf", "schädel"]),
+    ("ghost", &["gespenst", "geist"]),
+    ("poop", &["kackhaufen"]),
+    ("robot", &["roboter"]),
+    ("clap", &["klatschen", "applaus"]),
+    ("wave", &["winken", "hallo"]),
+    ("+1", &["daumen hoch", "gut"]),
+    ("-1", &["daumen runter", "schlecht"]),
+    ("fist", &["faust"]),
+    ("ok_hand", &["ok", "in ordnung"]),
+    ("muscle", &["bizeps", "stark"]),
+    ("pray", &["beten", "danke", "bitte"]),
+    ("handshake", &["händeschütteln", "handschlag"]),
+    ("crossed_fingers", &["daumen drücken"]),
+    ("eyes", &["augen"]),
+    ("baby", &["baby"]),
+    ("santa", &["weihnachtsmann"]),
+    ("dog", &["hund"]),
+    ("cat", &["katze"]),
+    ("mouse", &["maus"]),
+    ("rabbit", &["hase", "kaninchen"]),
+    ("fox_face", &["fuchs"]),
+    ("bear", &["bär"]),
+    ("tiger", &["tiger"]),
+    ("lion", &["löwe"]),
+    ("cow", &["kuh"]),
+    ("pig", &["schwein"]),
+    ("frog", &["frosch"]),
+    ("monkey_face", &["affe"]),
+    ("chicken", &["huhn"]),
+    ("penguin", &["pinguin"]),
+    ("bird", &["vogel"]),
+    ("duck", &["ente"]),
+    ("owl", &["eule"]),
+    ("horse", &["pferd"]),
+    ("unicorn", &["einhorn"]),
+    ("honeybee", &["biene"]),
+    ("butterfly", &["schmetterling"]),
+    ("snail", &["schnecke"]),
+    ("spider", &["spinne"]),
+    ("snake", &["schlange"]),
+    ("turtle", &["schildkröte"]),
+    ("apple", &["apfel"]),
+    ("banana", &["banane"]),
+    ("watermelon", &["wassermelone"]),
+    ("strawberry", &["erdbeere"]),
+    ("bread", &["brot"]),
+    ("cheese", &["käse"]),
+    ("pizza", &["pizza"]),
+    ("hamburger", &["hamburger"]),
+    ("cake", &["kuchen"]),
+    ("birthday", &["geburtstagskuchen", "geburtstag"]),
+    ("coffee", &["kaffee", "heißgetränk"]),
+    ("beer", &["bier"]),
+    ("beers", &["bierkrüge", "prost"]),
+    ("sunny", &["sonne"]),
+    ("cloud", &["wolke"]),
+    ("umbrella", &["regenschirm", "regen"]),
+    ("snowflake", &["schneeflocke", "schnee"]),
+    ("rainbow", &["regenbogen"]),
+    ("zap", &["blitz", "hochspannung"]),
+    ("fire", &["feuer", "flamme"]),
+    ("star", &["stern"]),
+    ("sparkles", &["funkeln", "sterne"]),
+    ("earth_africa", &["erde", "welt"]),
+    ("christmas_tree", &["weihnachtsbaum", "weihnachten"]),
+    ("gift", &["geschenk"]),
+    ("tada", &["konfettibombe", "party", "feiern"]),
+    ("trophy", &["pokal"]),
+    ("soccer", &["fußball"]),
+    ("rocket", &["rakete"]),
+    ("house", &["haus"]),
+    ("iphone", &["handy", "mobiltelefon"]),
+    ("computer", &["laptop", "rechner"]),
+    ("bulb", &["glühbirne", "idee"]),
+    ("moneybag", &["geldsack", "geld"]),
+    ("hourglass", &["sanduhr"]),
+    ("alarm_clock", &["wecker"]),
+    ("lock", &["schloss"]),
+    ("key", &["schlüssel"]),
+    ("hammer", &["hammer"]),
+    ("heart", &["herz", "liebe"]),
+    ("broken_heart", &["gebrochenes herz"]),
+    ("100", &["hundert punkte"]),
+    ("warning", &["warnung"]),
+    ("x", &["kreuz"]),
+    ("white_check_mark", &["häkchen", "erledigt"]),
+    ("question", &["fragezeichen"]),
+    ("exclamation", &["ausrufezeichen"]),
+];
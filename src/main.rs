@@ -1,105 +1,553 @@
 use std::{
-    convert::TryInto,
-    ptr,
-    sync::atomic::{AtomicPtr, AtomicUsize, Ordering},
+    borrow::Cow,
+    cmp::Reverse,
+    collections::{hash_map::RandomState, HashMap, VecDeque},
+    env,
+    hash::{BuildHasher, Hasher},
+    io, iter, panic, process,
+    sync::Arc,
+    time::{Duration, SystemTime},
 };
 
 use druid::{
-    lens, theme,
+    commands,
+    im::{self, Vector},
+    lens,
+    piet::Text,
+    text::{Attribute, RichText},
+    theme,
     widget::{
-        Container, Flex, Label, List, ListIter, MainAxisAlignment, Painter, Scroll,
-        TextBox,
+        Button, Container, Controller, CrossAxisAlignment, Either, Flex, Label,
+        LineBreaking, List, ListIter, MainAxisAlignment, Painter, RawLabel, Scroll,
+        SizedBox, TextBox, ViewSwitcher,
     },
-    AppDelegate, AppLauncher, Application, Color, Command, Data, DelegateCtx, Env,
-    EventCtx, Handled, Lens, LocalizedString, RenderContext, Selector, Target, UnitPoint,
-    Widget, WidgetExt, WindowDesc,
+    AppDelegate, AppLauncher, Application, Color, Command, Data, DelegateCtx, Env, Event,
+    EventCtx, FontWeight, Handled, KbKey, Lens, LifeCycle, LifeCycleCtx, LocalizedString,
+    Point, Rect, RenderContext, Screen, Selector, Target, TimerToken, UnitPoint,
+    UpdateCtx, Vec2, Widget, WidgetExt, WidgetId, WindowDesc, WindowHandle, WindowId,
+    WindowState,
 };
-use fuzzy_matcher as fz;
+use mouse_position::mouse_position::Mouse;
 
-mod mojis;
+mod cli;
+#[cfg(feature = "hotkey")]
+mod hotkey;
+mod palette;
+#[cfg(feature = "paste")]
+mod paste;
+mod persist;
+#[cfg(all(feature = "primary", target_os = "linux"))]
+mod primary;
+#[cfg(feature = "tray")]
+mod tray;
+#[cfg(all(feature = "tty", unix))]
+mod tty;
+#[cfg(target_os = "linux")]
+mod wayland;
+
+use emojay::{
+    codepoint_query, fold, is_literal_match, mojis, ranked_by_use, Category, Emoji,
+    Language, Matcher, Matching, OutputFormat, SkinTone, DEFAULT_THRESHOLD,
+};
 
 const COPY: Selector<Emoji> = Selector::new("emoji.copy");
+/// Copies an emoji but does the opposite of what `stay_open` says afterwards.
+const COPY_OTHERWISE: Selector<Emoji> = Selector::new("emoji.copy-otherwise");
+const FAVORITE: Selector<Emoji> = Selector::new("emoji.favorite");
+/// Hides an emoji from the results for good, or shows a hidden one again.
+const CONCEAL: Selector<Emoji> = Selector::new("emoji.conceal");
+const HOVER: Selector<Option<Emoji>> = Selector::new("emoji.hover");
+/// Filters the results again once the hidden emojis or the version filter changed
+/// outside of the grid's own events.
+const REFILTER: Selector = Selector::new("emoji.refilter");
+/// Fills the search box with a past search.
+const SEARCH_AGAIN: Selector<String> = Selector::new("emoji.search-again");
+/// Opens the details of an emoji in place of the grid.
+const DETAIL: Selector<Emoji> = Selector::new("emoji.detail");
+/// Adds an emoji to the ones "Copy all" copies together.
+const COLLECT: Selector<Emoji> = Selector::new("emoji.collect");
+/// Copies the collected emojis as one string and starts a new collection.
+const COPY_ALL: Selector = Selector::new("emoji.copy-all");
+/// Shows the picker if it is minimized and minimizes it otherwise.
+const SUMMON: Selector = Selector::new("emoji.summon");
+/// Brings the picker back from being minimized.
+const SHOW: Selector = Selector::new("emoji.show");
+/// Minimizes the picker after a copy unless it is set to stay open.
+#[cfg(not(feature = "paste"))]
+const HIDE: Selector = Selector::new("emoji.hide");
+/// Minimizes the picker and pastes what was just copied into the window under it.
+#[cfg(feature = "paste")]
+const PASTE: Selector = Selector::new("emoji.paste");
+
+/// How long a copied emoji's tile flashes and the confirmation is shown.
+const FLASH: Duration = Duration::from_millis(300);
+
+/// How long a failed copy is reported for.
+const ERROR_FOR: Duration = Duration::from_secs(4);
+
+/// How long a click waits to be the first of a double click before it copies.
+const DOUBLE_CLICK: Duration = Duration::from_millis(250);
+
+/// How long typing has to pause before the search is filtered.
+const DEBOUNCE: Duration = Duration::from_millis(120);
+
+/// How many past searches are offered under the search box.
+const SEARCH_HISTORY: usize = 20;
+
+/// How many results the grid shows at first, and how many more each "Show more" adds.
+const RESULTS_CAP: usize = 200;
+
+/// How far back the copies of the week are counted, in seconds.
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+/// How many of the most copied emojis the usage panel lists.
+const TOP_COPIED: usize = 10;
+
+/// How many filtered searches are remembered.
+const CACHED_FILTERS: usize = 64;
+
+/// About how wide a tile is, the grid fits as many columns as the window has room for.
+const TILE_WIDTH: f64 = 58.0;
+
+/// Windows narrower than this get a single column that names each emoji next to it
+/// instead of a squashed grid.
+const COMPACT_WIDTH: f64 = 180.0;
+
+/// How far the tiles can be zoomed out and in, and by how much per step.
+const MIN_SCALE: f64 = 0.5;
+const MAX_SCALE: f64 = 3.0;
+const ZOOM_STEP: f64 = 0.1;
+
+/// The number of columns the window first opens with unless the config or
+/// `EMOJAY_COLUMNS` says otherwise.
+const DEFAULT_COLUMNS: usize = 5;
+
+/// How many recently copied emojis are kept, the recents row shows as many as fit.
+const RECENTS: usize = 10;
 
-static INTERN: (AtomicUsize, AtomicPtr<(&'static str, &'static str)>) =
-    (AtomicUsize::new(0), AtomicPtr::new(ptr::null_mut()));
+/// Color emoji fonts to fall back on, in case the default font draws emojis in monochrome
+/// or not at all.
+const EMOJI_FONTS: &[&str] =
+    &["Noto Color Emoji", "Apple Color Emoji", "Segoe UI Emoji", "Twemoji", "JoyPixels"];
 
-struct EmojiCopy;
+/// Where a picked emoji goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Destination {
+    Clipboard,
+    /// Stdout is piped somewhere, the emoji is written there and the picker quits.
+    Stdout,
+    /// Typed into the terminal the picker was started from, which it then quits back to.
+    /// Falls back to the clipboard where the terminal won't take it.
+    Terminal,
+}
+
+struct EmojiCopy {
+    to: Destination,
+    #[cfg(all(feature = "primary", target_os = "linux"))]
+    primary: Option<primary::Primary>,
+    #[cfg(feature = "tray")]
+    tray: Option<tray::Tray>,
+}
+
+impl EmojiCopy {
+    fn new(to: Destination) -> Self {
+        EmojiCopy {
+            to,
+            #[cfg(all(feature = "primary", target_os = "linux"))]
+            primary: primary::Primary::new()
+                .map_err(|err| eprintln!("emojay: no primary selection, {}", err))
+                .ok(),
+            #[cfg(feature = "tray")]
+            tray: None,
+        }
+    }
+
+    /// Copies `text`, and pastes it or hides the picker unless it is to `stay_open`. A
+    /// failed copy leaves the picker as it is, so the error can be read.
+    fn put(
+        &mut self,
+        ctx: &mut DelegateCtx,
+        text: String,
+        stay_open: bool,
+    ) -> Result<(), String> {
+        if self.to == Destination::Stdout {
+            println!("{}", text);
+            ctx.submit_command(commands::QUIT_APP);
+            return Ok(());
+        }
+        #[cfg(all(feature = "tty", unix))]
+        if self.to == Destination::Terminal {
+            match tty::insert(&text) {
+                Ok(()) => {
+                    ctx.submit_command(commands::QUIT_APP);
+                    return Ok(());
+                }
+                Err(err) => eprintln!("emojay: can't type into the terminal, {}", err),
+            }
+        }
+        #[cfg(all(feature = "primary", target_os = "linux"))]
+        if let Some(primary) = &mut self.primary {
+            if let Err(err) = primary.set(&text) {
+                eprintln!("emojay: failed to set the primary selection, {}", err);
+            }
+        }
+        put_clipboard(text).inspect_err(|err| eprintln!("emojay: {}", err))?;
+        if !stay_open {
+            #[cfg(feature = "paste")]
+            ctx.submit_command(PASTE);
+            #[cfg(not(feature = "paste"))]
+            ctx.submit_command(HIDE);
+        }
+        Ok(())
+    }
+}
 
 impl AppDelegate<EmojiStuff> for EmojiCopy {
     fn command(
         &mut self,
-        _ctx: &mut DelegateCtx,
+        ctx: &mut DelegateCtx,
         _target: Target,
         cmd: &Command,
-        _data: &mut EmojiStuff,
+        data: &mut EmojiStuff,
         _env: &Env,
     ) -> Handled {
-        if let Some(emoji) = cmd.get(COPY) {
-            Application::global().clipboard().put_string(emoji.0.1);
+        let copy = cmd
+            .get(COPY)
+            .map(|emoji| (emoji, data.stay_open))
+            .or_else(|| cmd.get(COPY_OTHERWISE).map(|emoji| (emoji, !data.stay_open)));
+        if let Some((emoji, stay_open)) = copy {
+            match self.put(ctx, data.clip_text(*emoji).into_owned(), stay_open) {
+                Ok(()) => {
+                    data.push_recent(*emoji);
+                    data.copied = Some(*emoji);
+                }
+                Err(err) => data.copy_error = Some(err),
+            }
+            Handled::Yes
+        } else if cmd.is(COPY_ALL) {
+            let text =
+                data.collected.iter().map(|e| data.clip_text(*e)).collect::<String>();
+            if let Err(err) = self.put(ctx, text, data.stay_open) {
+                data.copy_error = Some(err);
+                return Handled::Yes;
+            }
+            for emoji in std::mem::replace(&mut data.collected, Vector::new()) {
+                data.push_recent(emoji);
+                data.copied = Some(emoji);
+            }
+            Handled::Yes
+        } else if let Some(emoji) = cmd.get(COLLECT) {
+            data.collected.push_back(*emoji);
+            Handled::Yes
+        } else if let Some(emoji) = cmd.get(FAVORITE) {
+            data.toggle_favorite(*emoji);
+            Handled::Yes
+        } else if let Some(emoji) = cmd.get(CONCEAL) {
+            data.toggle_hidden(*emoji);
+            Handled::Yes
+        } else if let Some(emoji) = cmd.get(DETAIL) {
+            data.detail = Some(*emoji);
+            Handled::Yes
+        } else if let Some(search) = cmd.get(SEARCH_AGAIN) {
+            data.search = search.clone();
+            Handled::Yes
+        } else if let Some(emoji) = cmd.get(HOVER) {
+            data.hovered = *emoji;
             Handled::Yes
         } else {
             Handled::No
         }
     }
+
+    /// The tray can only be added once the platform's event loop is running.
+    #[cfg(feature = "tray")]
+    fn window_added(
+        &mut self,
+        _id: WindowId,
+        data: &mut EmojiStuff,
+        _env: &Env,
+        ctx: &mut DelegateCtx,
+    ) {
+        if self.tray.is_none() {
+            self.tray = tray::Tray::new(ctx.get_external_handle())
+                .map_err(|err| eprintln!("emojay: no tray icon, {}", err))
+                .ok();
+        }
+        data.in_tray = self.tray.is_some();
+    }
 }
 
-/// The text description and the emoji.
-#[derive(Debug, Clone, Copy, Data)]
-#[repr(transparent)]
-struct Emoji((&'static str, &'static str));
+/// Fills out the last row of the grid, with nothing to draw or name.
+const BLANK: Emoji =
+    Emoji { name: "", glyph: "", group: Category::Symbols, keywords: &[] };
 
-#[derive(Debug, Clone, Copy)]
-#[repr(transparent)]
-struct EmojiList(&'static [Emoji]);
+/// The emojis currently shown in the grid, cheap to clone, and how many of the first of
+/// them matched the search as typed rather than fuzzily.
+#[derive(Debug, Clone)]
+struct EmojiList(Arc<[Emoji]>, usize);
 
 impl EmojiList {
-    pub fn new(emoji: &'static [(&'static str, &'static str)]) -> Self {
-        unsafe { std::mem::transmute(emoji) }
+    /// The emojis of `emoji` that are to be kept.
+    pub fn new(emoji: &'static [Emoji], keep: impl Fn(&Emoji) -> bool) -> Self {
+        EmojiList(emoji.iter().copied().filter(keep).collect(), 0)
     }
 
-    fn filter(&self, search: &str) -> Self {
-        use fz::FuzzyMatcher;
-        let matcher = fz::clangd::ClangdMatcher::default();
+    /// Filters the whole of `data.source` by the search and category of `data`, or just
+    /// `self` when it holds the results of `previous`, a shorter prefix of the
+    /// search, as typing forward only narrows the matches.
+    fn filter(&self, previous: Option<&str>, data: &EmojiStuff) -> Self {
+        let (search, category) = (&*data.search, data.category);
+        // A pasted emoji is looked up rather than searched for, so its name shows, with
+        // the rest of its subgroup after it.
+        if let Some(pasted) = pasted(data.source, search).filter(|e| data.is_visible(e)) {
+            let related = data.source.iter().copied().filter(|e| {
+                !pasted.subgroup().is_empty()
+                    && e.subgroup() == pasted.subgroup()
+                    && e.name != pasted.name
+                    && data.is_visible(e)
+            });
+            return EmojiList(iter::once(pasted).chain(related).collect(), 1);
+        }
+        // Codepoints, emojis and groups aren't matched by prefix, so those searches
+        // always start over. So do typos, a few more letters can turn a slip nothing
+        // matched into one that is forgiven.
+        let codepoint = codepoint_query(search);
+        let exact = codepoint.is_some()
+            || mojis::is_subgroup(search)
+            || Category::named(search).is_some();
+        let narrow = previous.is_some_and(|old| {
+            !old.is_empty()
+                && !data.typos
+                && search.starts_with(old)
+                && !exact
+                && codepoint_query(old).is_none()
+                // Typing after a pasted emoji isn't a narrower search of it.
+                && old.is_ascii()
+        });
+        let uses = |e: &Emoji| data.counts.get(&e.name).copied().unwrap_or(0);
+        let source = if narrow { &*self.0 } else { data.source };
+        let since = |e: &Emoji| data.min_version.is_none_or(|min| e.version() >= min);
+        let list = ranked_by_use(
+            source.iter().copied().filter(|e| data.is_visible(e)).filter(since),
+            search,
+            category,
+            data.threshold,
+            Matching {
+                matcher: data.matcher,
+                typos: data.typos,
+                language: data.language,
+            },
+            uses,
+        );
 
-        let list: &'static [Emoji] = mojis::EMOJIS
-            .iter()
-            .copied()
-            .filter(|e| {
-                e.0.contains(search)
-                    || search.is_empty()
-                    || matcher
-                        .fuzzy_match(e.0, search)
-                        .map(|score| score > 25)
-                        .unwrap_or(false)
-            })
-            .map(Emoji)
-            .collect::<Vec<_>>()
-            .leak();
+        let keep = |e: &Emoji| {
+            category.is_none_or(|cat| e.group == cat) && data.is_visible(e) && since(e)
+        };
+        let (list, found) = with_synonyms(list, search, keep, &data.synonyms);
+        // Literal matches are sorted first, so they are all at the front, after the
+        // synonyms.
+        let literal = found
+            + list[found..]
+                .iter()
+                .take_while(|e| is_literal_match(e, search, data.language))
+                .count();
+        EmojiList(list.into(), literal)
+    }
+}
 
-        let len = list.len();
-        let ptr = list.as_ptr() as *mut (&'static str, &'static str);
+/// The emoji of `source` that `search` is the glyph of, in any skin tone and with or
+/// without variation selectors.
+fn pasted(source: &[Emoji], search: &str) -> Option<Emoji> {
+    fn bare(glyph: &str) -> impl Iterator<Item = char> + '_ {
+        glyph.chars().filter(|&c| !matches!(c, '\u{FE0F}' | '\u{1F3FB}'..='\u{1F3FF}'))
+    }
+    let search = search.trim();
+    if search.is_ascii() || bare(search).next().is_none() {
+        return None;
+    }
+    source.iter().find(|e| bare(e.glyph).eq(bare(search))).copied()
+}
 
-        let (l, p) = &INTERN;
-        let pointer = p.load(Ordering::SeqCst);
-        if !pointer.is_null() {
-            let length = l.load(Ordering::SeqCst);
-            unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(pointer, length)) };
-        }
-        l.store(len, Ordering::SeqCst);
-        p.store(ptr, Ordering::SeqCst);
+/// The user's own search terms and the emojis each of them finds.
+type Synonyms = HashMap<String, Vec<Emoji>>;
+
+/// Reads the configured synonyms, dropping the emojis of `source` that aren't known by
+/// the name or glyph they were given as, or are newer than `max_version`.
+fn synonyms(config: &persist::Config, source: &[Emoji]) -> Synonyms {
+    let supported = supported(config.max_version);
+    config
+        .synonyms
+        .iter()
+        .map(|(term, names)| {
+            let emojis = names
+                .iter()
+                .filter_map(|name| {
+                    source
+                        .iter()
+                        .find(|e| e.name == name || e.glyph == name.as_str())
+                        .filter(|e| supported(e))
+                        .copied()
+                })
+                .collect();
+            (fold(term).into_owned(), emojis)
+        })
+        .collect()
+}
 
-        EmojiList(list)
+/// Puts the emojis of the synonyms `search` starts ahead of `list` that are to be kept,
+/// the closest synonyms first, and says how many were put there. Typing forward keeps
+/// starting the same synonyms, so narrowing still works.
+fn with_synonyms(
+    list: Vec<Emoji>,
+    search: &str,
+    keep: impl Fn(&Emoji) -> bool,
+    synonyms: &Synonyms,
+) -> (Vec<Emoji>, usize) {
+    let search = &*fold(search);
+    if search.is_empty() || synonyms.is_empty() {
+        return (list, 0);
     }
+    let mut terms =
+        synonyms.iter().filter(|(term, _)| term.starts_with(search)).collect::<Vec<_>>();
+    if terms.is_empty() {
+        return (list, 0);
+    }
+    terms.sort_by_key(|(term, _)| (term.len(), term.as_str()));
+    let mut found = Vec::<Emoji>::new();
+    for emoji in terms.into_iter().flat_map(|(_, emojis)| emojis.iter().copied()) {
+        if keep(&emoji) && !found.iter().any(|e| e.name == emoji.name) {
+            found.push(emoji);
+        }
+    }
+    let rest = list
+        .into_iter()
+        .filter(|e| !found.iter().any(|f| f.name == e.name))
+        .collect::<Vec<_>>();
+    let count = found.len();
+    found.extend(rest);
+    (found, count)
+}
+
+/// Whether an emoji is from `max_version` or before, with no limit they all are.
+fn supported(max_version: Option<f64>) -> impl Fn(&Emoji) -> bool {
+    move |emoji| max_version.is_none_or(|max| emoji.version() <= max)
+}
+
+/// The emoji that best matches `query` across every category.
+fn best_match(query: &str) -> Option<Emoji> {
+    let config = with_env(persist::Config::load());
+    let counts = persist::Saved::load().counts;
+    let uses = |e: &Emoji| counts.get(e.name).copied().unwrap_or(0);
+    let list = ranked_by_use(
+        mojis::EMOJIS.iter().copied().filter(supported(config.max_version)),
+        query,
+        None,
+        config.threshold,
+        Matching {
+            matcher: config.matcher,
+            typos: config.typos,
+            language: config.language,
+        },
+        uses,
+    );
+    with_synonyms(list, query, |_| true, &synonyms(&config, mojis::EMOJIS))
+        .0
+        .first()
+        .copied()
 }
 
 impl Data for EmojiList {
-    fn same(&self, other: &Self) -> bool { self.data_len() == other.data_len() }
+    fn same(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
 }
 
 #[derive(Clone, Debug, Data, Lens)]
 struct EmojiStuff {
+    /// Every emoji the picker offers, the bundled set unless it was given another.
+    #[data(ignore)]
+    source: &'static [Emoji],
     search: String,
     emojis: EmojiList,
+    /// The index into `emojis` highlighted by keyboard navigation.
+    selected: Option<usize>,
+    /// The last few copied emojis, newest first.
+    recent: Vector<Emoji>,
+    /// The searches emojis were copied from, newest first.
+    searches: Vector<String>,
+    /// Emojis pinned by right clicking them, in the order they were pinned.
+    favorites: Vector<Emoji>,
+    /// How many times each emoji has been copied, breaks ties between equally good
+    /// matches.
+    counts: im::HashMap<&'static str, u32>,
+    /// When the copies of the last week were made, oldest first.
+    copied_at: Vector<u64>,
+    /// Whether the usage panel is shown in place of the grid.
+    showing_stats: bool,
+    /// The tab the grid is narrowed to, `None` shows every category.
+    category: Option<Category>,
+    /// Applied to emojis that support a skin tone when they are copied.
+    skin_tone: SkinTone,
+    /// How a copied emoji is written out.
+    format: OutputFormat,
+    /// The fuzzy match score an emoji has to beat to be shown.
+    threshold: i64,
+    /// How searches are fuzzy matched.
+    matcher: Matcher,
+    /// Whether misspelled searches still find what they were meant to.
+    typos: bool,
+    /// The language searched in besides English.
+    language: Language,
+    /// The newest Emoji version shown, the fonts of older systems draw later emojis as
+    /// boxes.
+    max_version: Option<f64>,
+    /// Only emojis from this Emoji version on are shown, to browse the newest ones.
+    min_version: Option<f64>,
+    /// Emojis left out of the results altogether, by choice.
+    hidden: Vector<Emoji>,
+    /// Whether the hidden emojis are listed in place of the grid, to show them again.
+    showing_hidden: bool,
+    /// The emoji under the mouse, named in place of the chosen one.
+    hovered: Option<Emoji>,
+    /// The number of emojis in each row of the grid, also how many recents are shown.
+    columns: usize,
+    /// The palette picked with the toggle, `None` follows the OS.
+    light: Option<bool>,
+    /// Whether the OS was set to a light appearance when the picker started.
+    system_light: bool,
+    /// How much bigger than normal the tiles and their emojis are drawn.
+    scale: f64,
+    /// The emoji that was just copied, shown for a moment to confirm the copy.
+    copied: Option<Emoji>,
+    /// Why the last copy didn't make it to the clipboard, shown for a while.
+    copy_error: Option<String>,
+    /// Emojis shift clicked to be copied together, in the order they were clicked.
+    collected: Vector<Emoji>,
+    /// The font family emojis are drawn in, `None` leaves them to the UI font. Starts as
+    /// the configured family until the window checks what is installed.
+    font: Option<Arc<str>>,
+    /// Whether the picker is shown next to the mouse cursor instead of where it was
+    /// left.
+    at_cursor: bool,
+    /// The emoji whose details are shown over the grid.
+    detail: Option<Emoji>,
+    /// Whether the picker is left open after a copy instead of getting out of the way.
+    stay_open: bool,
+    /// The user's own search terms, these only change on a restart.
+    #[data(ignore)]
+    synonyms: Arc<Synonyms>,
+    /// How many of the results the grid shows, rounded up to whole rows.
+    limit: usize,
+    /// How far down the grid is scrolled, so rebuilding it doesn't lose the place.
+    #[data(ignore)]
+    scroll_offset: f64,
+    /// Where the window was last closed, only kept to be saved.
+    #[data(ignore)]
+    window: Option<persist::Geometry>,
+    /// The preferences changed from the picker over the config's, only kept to be saved.
+    #[data(ignore)]
+    toggles: persist::Toggles,
+    /// Whether there is a tray icon to bring the picker back from, closing the window
+    /// only minimizes it then.
+    in_tray: bool,
 }
 
 #[derive(Clone, Debug, Data, Lens)]
@@ -107,37 +555,514 @@ struct EmojiState {
     stuff: EmojiStuff,
 }
 
-impl ListIter<[Emoji; 5]> for EmojiList {
-    fn for_each(&self, mut cb: impl FnMut(&[Emoji; 5], usize)) {
-        for (i, e) in self.0.chunks(5).enumerate() {
-            let mut e = e.to_vec();
-            for _ in e.len()..5 {
-                e.push(Emoji((" ", "0")))
-            }
-            let e: [Emoji; 5] =
-                e.try_into().expect("there are 1570 emojis evenly divisible by 5");
-            cb(&e, i)
+/// One row of the grid and the column of the selected emoji, if it is in this row.
+///
+/// Rows are a window into the shared list rather than a copy of their emojis, so building
+/// one for every row on every pass doesn't allocate.
+#[derive(Clone, Debug)]
+struct EmojiRow {
+    /// The list the row's emojis are in, starting at `start`.
+    emojis: Arc<[Emoji]>,
+    start: usize,
+    columns: usize,
+    selected: Option<usize>,
+    /// Whether this is a row of favorites.
+    pinned: bool,
+    /// The column of the emoji that was just copied, it flashes for a moment.
+    copied: Option<usize>,
+    /// How many of the row's emojis, from the first, matched the search as typed.
+    literal: usize,
+    /// Whether the emojis are drawn in an emoji font rather than the UI font.
+    emoji_font: bool,
+}
+
+impl EmojiRow {
+    /// The emoji in `column`, `BLANK` past the end of the last row.
+    fn emoji(&self, column: usize) -> Emoji {
+        self.chunk().get(column).copied().unwrap_or(BLANK)
+    }
+
+    /// Whether `column` only pads out the last row.
+    fn is_padding(&self, column: usize) -> bool { column >= self.chunk().len() }
+
+    /// The emojis in the row, which falls short of `columns` only for the last row.
+    fn chunk(&self) -> &[Emoji] {
+        let end = (self.start + self.columns).min(self.emojis.len());
+        &self.emojis[self.start.min(end)..end]
+    }
+}
+
+/// Rows are rebuilt on every pass, so they are compared by content rather than by
+/// pointer.
+impl Data for EmojiRow {
+    fn same(&self, other: &Self) -> bool {
+        self.selected == other.selected
+            && self.pinned == other.pinned
+            && self.copied == other.copied
+            && self.literal == other.literal
+            && self.columns == other.columns
+            && ((Arc::ptr_eq(&self.emojis, &other.emojis) && self.start == other.start)
+                || (self.chunk().len() == other.chunk().len()
+                    && self.chunk().iter().zip(other.chunk()).all(|(a, b)| a.same(b))))
+    }
+}
+
+impl EmojiStuff {
+    /// The state the picker opens with, over the emojis of `source` rather than the
+    /// bundled ones if it is given others. Nothing is taken from the environment or the
+    /// OS, `started` adds that.
+    fn new(
+        source: &'static [Emoji],
+        config: &persist::Config,
+        saved: persist::Saved,
+    ) -> Self {
+        let find = |name: &str| source.iter().find(|e| e.name == name).copied();
+        let columns = columns(config);
+        let hidden =
+            saved.hidden.iter().filter_map(|name| find(name)).collect::<Vector<_>>();
+        let visible = |e: &Emoji| {
+            supported(config.max_version)(e) && !hidden.iter().any(|h| h.name == e.name)
+        };
+        EmojiStuff {
+            source,
+            search: String::new(),
+            emojis: EmojiList::new(source, visible),
+            selected: None,
+            recent: saved
+                .recent
+                .iter()
+                .filter_map(|name| find(name))
+                .take(RECENTS)
+                .collect(),
+            favorites: saved.favorites.iter().filter_map(|name| find(name)).collect(),
+            searches: saved.searches.iter().take(SEARCH_HISTORY).cloned().collect(),
+            counts: saved
+                .counts
+                .iter()
+                .filter_map(|(name, &n)| Some((find(name)?.name, n)))
+                .collect(),
+            copied_at: saved
+                .copied_at
+                .iter()
+                .copied()
+                .filter(|&at| at + WEEK > now())
+                .collect(),
+            showing_stats: false,
+            category: None,
+            skin_tone: config.default_tone,
+            format: config.format,
+            hovered: None,
+            threshold: config.threshold,
+            matcher: config.matcher,
+            typos: config.typos,
+            language: config.language,
+            max_version: config.max_version,
+            min_version: None,
+            hidden,
+            showing_hidden: false,
+            columns,
+            light: config.light,
+            system_light: false,
+            scale: scale(config),
+            copied: None,
+            copy_error: None,
+            collected: Vector::new(),
+            font: config.font.as_deref().map(Into::into),
+            at_cursor: config.at_cursor,
+            stay_open: config.stay_open,
+            detail: None,
+            synonyms: Arc::new(synonyms(config, source)),
+            limit: RESULTS_CAP,
+            scroll_offset: 0.0,
+            window: saved.window,
+            toggles: saved.toggles,
+            // The tray is only added with the window, if it can be at all.
+            in_tray: false,
         }
     }
 
-    fn for_each_mut(&mut self, mut cb: impl FnMut(&mut [Emoji; 5], usize)) {
-        for (i, e) in self.0.chunks(5).enumerate() {
-            let mut e = e.to_vec();
-            for _ in e.len()..5 {
-                e.push(Emoji((" ", "0")))
-            }
-            let mut e: [Emoji; 5] =
-                e.try_into().expect("there are 1570 emojis evenly divisible by 5");
-            cb(&mut e, i)
+    /// The state the picker opens with, searching for `EMOJAY_SEARCH` and following the
+    /// palette the OS is set to.
+    fn started(
+        source: &'static [Emoji],
+        config: &persist::Config,
+        saved: persist::Saved,
+    ) -> Self {
+        EmojiStuff {
+            // Scripts can open the picker already searching for something.
+            search: env::var("EMOJAY_SEARCH").unwrap_or_default(),
+            // Detection isn't available everywhere, those platforms get the dark palette.
+            system_light: dark_light::detect() == dark_light::Mode::Light,
+            ..Self::new(source, config, saved)
+        }
+    }
+
+    fn row(&self, idx: usize) -> EmojiRow {
+        let columns = self.columns;
+        let selected =
+            self.selected.filter(|sel| sel / columns == idx).map(|sel| sel % columns);
+        let literal = self.emojis.1.saturating_sub(idx * columns).min(columns);
+        EmojiRow { literal, ..self.padded(&self.emojis.0, idx * columns, selected) }
+    }
+
+    /// The row of `emojis` from `start` that knows whether one of its emojis was just
+    /// copied.
+    fn padded(
+        &self,
+        emojis: &Arc<[Emoji]>,
+        start: usize,
+        selected: Option<usize>,
+    ) -> EmojiRow {
+        let mut row = EmojiRow {
+            emojis: emojis.clone(),
+            start,
+            columns: self.columns,
+            selected,
+            pinned: false,
+            copied: None,
+            literal: 0,
+            emoji_font: self.font.is_some(),
+        };
+        row.copied = self.copied.and_then(|copied| {
+            row.chunk().iter().position(|emoji| emoji.name == copied.name)
+        });
+        row
+    }
+
+    fn recent_row(&self) -> EmojiRow {
+        self.padded(&self.recent.iter().copied().collect(), 0, None)
+    }
+
+    fn favorite_rows(&self) -> Vector<EmojiRow> {
+        let favorites = self.favorites.iter().copied().collect::<Arc<[Emoji]>>();
+        (0..favorites.len())
+            .step_by(self.columns)
+            .map(|start| EmojiRow {
+                pinned: true,
+                ..self.padded(&favorites, start, None)
+            })
+            .collect()
+    }
+
+    /// Whether `emoji` can show up in the results, it isn't hidden or too new.
+    fn is_visible(&self, emoji: &Emoji) -> bool {
+        supported(self.max_version)(emoji)
+            && !self.hidden.iter().any(|e| e.name == emoji.name)
+    }
+
+    /// The version filter and the number of hidden emojis, besides the search and
+    /// category what the results depend on.
+    fn filters(&self) -> (Option<f64>, usize) { (self.min_version, self.hidden.len()) }
+
+    fn toggle_hidden(&mut self, emoji: Emoji) {
+        let len = self.hidden.len();
+        self.hidden.retain(|e| e.name != emoji.name);
+        if self.hidden.len() == len {
+            self.hidden.push_back(emoji);
+        }
+        if self.hidden.is_empty() {
+            self.showing_hidden = false;
+        }
+        self.save();
+    }
+
+    fn toggle_favorite(&mut self, emoji: Emoji) {
+        let len = self.favorites.len();
+        self.favorites.retain(|e| e.name != emoji.name);
+        if self.favorites.len() == len {
+            self.favorites.push_back(emoji);
+        }
+        self.save();
+    }
+
+    /// Remembers a copy of `emoji`, for the recents row and the ranking, and the search
+    /// it was found with.
+    fn push_recent(&mut self, emoji: Emoji) {
+        let count = self.counts.get(&emoji.name).copied().unwrap_or(0);
+        self.counts.insert(emoji.name, count + 1);
+        let now = now();
+        self.copied_at.retain(|&at| at + WEEK > now);
+        self.copied_at.push_back(now);
+        self.recent.retain(|e| e.name != emoji.name);
+        self.recent.push_front(emoji);
+        self.recent.truncate(RECENTS);
+        let search = self.search.trim();
+        if !search.is_empty() {
+            self.searches.retain(|old| old != search);
+            self.searches.push_front(search.to_owned());
+            self.searches.truncate(SEARCH_HISTORY);
+        }
+        self.save();
+    }
+
+    /// The most copied emojis and how often they were, most first.
+    fn top_copied(&self) -> Vector<(Emoji, u32)> {
+        let mut counts = self
+            .counts
+            .iter()
+            .filter_map(|(&name, &n)| {
+                Some((*self.source.iter().find(|e| e.name == name)?, n))
+            })
+            .collect::<Vec<_>>();
+        counts.sort_by_key(|&(emoji, n)| (Reverse(n), emoji.name));
+        counts.into_iter().take(TOP_COPIED).collect()
+    }
+
+    fn copies_this_week(&self) -> usize {
+        let now = now();
+        self.copied_at.iter().filter(|&&at| at + WEEK > now).count()
+    }
+
+    /// Forgets how often everything was copied, which the ranking goes back to ignoring.
+    fn clear_stats(&mut self) {
+        self.counts.clear();
+        self.copied_at.clear();
+        self.save();
+    }
+
+    /// The past searches that what is typed so far could be finishing, newest first.
+    fn history(&self) -> Vector<String> {
+        let search = self.search.trim();
+        self.searches
+            .iter()
+            .filter(|old| old.starts_with(search) && old.as_str() != search)
+            .take(5)
+            .cloned()
+            .collect()
+    }
+
+    fn save(&self) {
+        let names =
+            |list: &Vector<Emoji>| list.iter().map(|e| e.name.to_owned()).collect();
+        let saved = persist::Saved {
+            recent: names(&self.recent),
+            favorites: names(&self.favorites),
+            hidden: names(&self.hidden),
+            window: self.window,
+            counts: self.counts.iter().map(|(name, &n)| (name.to_string(), n)).collect(),
+            copied_at: self.copied_at.iter().copied().collect(),
+            searches: self.searches.iter().cloned().collect(),
+            toggles: self.toggles,
+        };
+        if let Err(err) = saved.save() {
+            eprintln!("failed to save state: {}", err);
+        }
+    }
+
+    /// Saves a preference changed from the picker, from now on it is kept over the
+    /// config's until that is edited.
+    fn save_toggles(&mut self) {
+        let config = persist::Config::load();
+        self.toggles = persist::Toggles {
+            light: persist::Toggled::new(self.light, config.light),
+            scale: persist::Toggled::new(self.scale, config.scale),
+            stay_open: persist::Toggled::new(self.stay_open, config.stay_open),
+            format: persist::Toggled::new(self.format, config.format),
+        };
+        self.save();
+    }
+
+    /// Moves on to the next output format, which is kept for the next run too.
+    fn next_format(&mut self) {
+        self.format = self.format.next();
+        self.save_toggles();
+    }
+
+    /// Grows or shrinks the tiles, returns `false` if they're already as big or small as
+    /// they go.
+    fn zoom(&mut self, by: f64) -> bool {
+        let scale = (self.scale + by).clamp(MIN_SCALE, MAX_SCALE);
+        if scale == self.scale {
+            return false;
         }
+        self.scale = scale;
+        self.save_toggles();
+        true
+    }
+
+    /// What copying `emoji` puts on the clipboard.
+    fn clip_text(&self, emoji: Emoji) -> Cow<'static, str> {
+        self.format.apply(emoji, self.skin_tone)
+    }
+
+    fn is_light(&self) -> bool { self.light.unwrap_or(self.system_light) }
+
+    /// The selected emoji, or the top result when nothing is selected.
+    fn chosen(&self) -> Option<Emoji> {
+        let list = &self.emojis.0;
+        self.selected.and_then(|i| list.get(i)).or_else(|| list.first()).copied()
+    }
+
+    /// How many results are in the grid, `limit` of them filled out to the end of their
+    /// row.
+    fn shown(&self) -> usize {
+        let rows = self.limit.div_ceil(self.columns);
+        self.emojis.0.len().min(rows * self.columns)
     }
 
-    fn data_len(&self) -> usize { self.0.len() }
+    /// Any one of the shown emojis, every emoji when nothing is searched for.
+    fn random(&self) -> Option<Emoji> {
+        let list = &self.emojis.0;
+        // Each `RandomState` is seeded differently, which is all the randomness this
+        // needs.
+        let roll = RandomState::new().build_hasher().finish() as usize;
+        list.get(roll % list.len().max(1)).copied()
+    }
+
+    /// Moves the grid selection according to an arrow key, returns `false` if the key
+    /// doesn't move the selection.
+    ///
+    /// Left and right only move an existing selection so they still work in the search
+    /// box, moving up out of the first row hands control back to the search box.
+    fn move_selection(&mut self, key: &KbKey) -> bool {
+        let last = match self.shown().checked_sub(1) {
+            Some(last) => last,
+            None => return false,
+        };
+        self.selected = match (key, self.selected) {
+            (KbKey::ArrowDown, None) => Some(0),
+            (KbKey::ArrowDown, Some(i)) => Some((i + self.columns).min(last)),
+            (KbKey::ArrowUp, Some(i)) => i.checked_sub(self.columns),
+            (KbKey::ArrowLeft, Some(i)) => Some(i.saturating_sub(1)),
+            (KbKey::ArrowRight, Some(i)) => Some((i + 1).min(last)),
+            _ => return false,
+        };
+        true
+    }
+}
+
+impl ListIter<EmojiRow> for EmojiStuff {
+    fn for_each(&self, mut cb: impl FnMut(&EmojiRow, usize)) {
+        for i in 0..self.data_len() {
+            cb(&self.row(i), i)
+        }
+    }
+
+    fn for_each_mut(&mut self, mut cb: impl FnMut(&mut EmojiRow, usize)) {
+        for i in 0..self.data_len() {
+            cb(&mut self.row(i), i)
+        }
+    }
+
+    fn data_len(&self) -> usize { self.shown().div_ceil(self.columns) }
+}
+
+/// Recently filtered results, so backspacing or retyping a search doesn't filter again.
+#[derive(Default)]
+struct FilterCache {
+    lists: HashMap<(String, Option<Category>), EmojiList>,
+    /// The keys of `lists`, least recently used first.
+    order: VecDeque<(String, Option<Category>)>,
+}
+
+impl FilterCache {
+    fn get_or_insert_with(
+        &mut self,
+        key: (String, Option<Category>),
+        filter: impl FnOnce() -> EmojiList,
+    ) -> EmojiList {
+        if let Some(pos) = self.order.iter().position(|used| *used == key) {
+            let key = self.order.remove(pos).expect("position is in bounds");
+            self.order.push_back(key);
+        } else {
+            if self.order.len() == CACHED_FILTERS {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.lists.remove(&oldest);
+                }
+            }
+            self.order.push_back(key.clone());
+        }
+        self.lists.entry(key).or_insert_with(filter).clone()
+    }
 }
 
 struct EmojiPane {
     list: Flex<EmojiStuff>,
+    /// The search and category last seen, a changed search is only filtered with once
+    /// typing pauses.
+    last_search: String,
+    last_category: Option<Category>,
+    /// The version filter and number of hidden emojis the results were filtered with.
+    last_filters: (Option<f64>, usize),
+    /// The pending filter of a changed search.
+    filter_timer: TimerToken,
+    /// Ends the confirmation of the last copy.
+    flash_timer: TimerToken,
+    /// Ends the report of a failed copy.
+    error_timer: TimerToken,
+    cache: FilterCache,
+    /// The search and category `EmojiStuff::emojis` holds the results of.
+    filtered: (String, Option<Category>),
+    search_id: WidgetId,
+    /// The width of the window, `None` until it is first sized.
+    width: Option<f64>,
+}
+
+impl EmojiPane {
+    fn show(&self, ctx: &mut EventCtx, at_cursor: bool) {
+        let mut window = ctx.window().clone();
+        window.set_window_state(WindowState::Restored);
+        if at_cursor {
+            move_to_cursor(&window);
+        }
+        window.bring_to_front_and_focus();
+        ctx.set_focus(self.search_id);
+    }
+
+    fn refilter(&mut self, data: &mut EmojiStuff) {
+        let key = (data.search.clone(), data.category);
+        let filtered = &self.filtered;
+        let previous = Some(filtered.0.as_str()).filter(|_| filtered.1 == data.category);
+        data.emojis = self
+            .cache
+            .get_or_insert_with(key.clone(), || data.emojis.filter(previous, data));
+        if key != self.filtered {
+            data.scroll_offset = 0.0;
+            data.limit = RESULTS_CAP;
+        }
+        self.filtered = key;
+        data.selected = None;
+        self.filter_timer = TimerToken::INVALID;
+    }
+
+    /// Extends the search to the longest start the descriptions beginning with it share,
+    /// all the way to a lone match, which is then selected. Returns whether the search
+    /// changed.
+    fn complete(&mut self, data: &mut EmojiStuff) -> bool {
+        if self.filter_timer != TimerToken::INVALID {
+            self.refilter(data);
+        }
+        let search = fold(&data.search).into_owned();
+        if search.is_empty() {
+            return false;
+        }
+        let mut names =
+            data.emojis.0.iter().map(|e| e.name).filter(|n| n.starts_with(&search));
+        let first = match names.next() {
+            Some(first) => first,
+            None => return false,
+        };
+        let (prefix, count) = names.fold((first, 1), |(prefix, count), name| {
+            let shared = prefix
+                .char_indices()
+                .zip(name.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(prefix.len().min(name.len()), |((idx, _), _)| idx);
+            (&prefix[..shared], count + 1)
+        });
+        if prefix.len() <= search.len() {
+            return false;
+        }
+        data.search = prefix.to_owned();
+        self.refilter(data);
+        self.last_search = data.search.clone();
+        if count == 1 {
+            data.selected = data.emojis.0.iter().position(|e| e.name == prefix);
+        }
+        true
+    }
 }
+
 impl Widget<EmojiStuff> for EmojiPane {
     fn event(
         &mut self,
@@ -146,8 +1071,221 @@ impl Widget<EmojiStuff> for EmojiPane {
         data: &mut EmojiStuff,
         env: &Env,
     ) {
-        data.emojis = data.emojis.filter(&data.search);
-        self.list.event(ctx, event, data, env);
+        if let Event::KeyDown(key) = event {
+            let handled = match &key.key {
+                KbKey::Enter => {
+                    if self.filter_timer != TimerToken::INVALID {
+                        self.refilter(data);
+                    }
+                    if let Some(emoji) = data.chosen() {
+                        ctx.submit_command(COPY.with(emoji));
+                    }
+                    true
+                }
+                KbKey::Escape if data.detail.is_some() => {
+                    data.detail = None;
+                    true
+                }
+                KbKey::Escape => {
+                    data.search.clear();
+                    data.selected = None;
+                    ctx.set_focus(self.search_id);
+                    true
+                }
+                // With only the search box and the grid to visit, Tab and Shift+Tab both
+                // just swap between them.
+                KbKey::Tab if ctx.is_focused() => {
+                    ctx.set_focus(self.search_id);
+                    true
+                }
+                // In the search box Tab first completes a partial description, once there
+                // is nothing left to complete it moves on to the grid.
+                KbKey::Tab if !key.mods.shift() && self.complete(data) => true,
+                KbKey::Tab => {
+                    if data.selected.is_none() && !data.emojis.0.is_empty() {
+                        data.selected = Some(0);
+                    }
+                    ctx.request_focus();
+                    true
+                }
+                // Alt+1 to Alt+9 copy that result straight away.
+                KbKey::Character(c) if key.mods.alt() => match c.parse::<usize>() {
+                    Ok(n @ 1..=9) => {
+                        if self.filter_timer != TimerToken::INVALID {
+                            self.refilter(data);
+                        }
+                        if let Some(&emoji) = data.emojis.0.get(n - 1) {
+                            ctx.submit_command(COPY.with(emoji));
+                        }
+                        true
+                    }
+                    _ => false,
+                },
+                KbKey::Character(c) if key.mods.ctrl() => match c.as_str() {
+                    "+" | "=" => data.zoom(ZOOM_STEP),
+                    "-" => data.zoom(-ZOOM_STEP),
+                    "0" => data.zoom(1.0 - data.scale),
+                    "M" | "m" => {
+                        data.next_format();
+                        true
+                    }
+                    // The details of the emoji Enter would copy, as a double click opens.
+                    "I" | "i" => {
+                        if let Some(emoji) = data.chosen() {
+                            data.detail = Some(emoji);
+                        }
+                        true
+                    }
+                    // Copies the last copied emoji again without searching for it.
+                    "V" | "v" if key.mods.shift() => {
+                        if let Some(&emoji) = data.recent.front() {
+                            ctx.submit_command(COPY.with(emoji));
+                        }
+                        true
+                    }
+                    _ => false,
+                },
+                key => {
+                    let moved = data.move_selection(key);
+                    // Moving up out of the grid goes back to typing.
+                    if moved && data.selected.is_none() && ctx.is_focused() {
+                        ctx.set_focus(self.search_id);
+                    }
+                    moved
+                }
+            };
+            if handled {
+                ctx.set_handled();
+            }
+        }
+
+        if let Event::WindowCloseRequested = event {
+            let (position, size) = (ctx.window().get_position(), ctx.window().get_size());
+            data.window = Some(persist::Geometry {
+                x: position.x,
+                y: position.y,
+                height: size.height,
+            });
+            data.save();
+        }
+
+        match event {
+            Event::Timer(token) if *token == self.filter_timer => self.refilter(data),
+            Event::Timer(token) if *token == self.flash_timer => data.copied = None,
+            Event::Timer(token) if *token == self.error_timer => data.copy_error = None,
+            Event::WindowConnected => {
+                // A search set from the environment is shown filtered from the start.
+                if !data.search.is_empty() {
+                    self.refilter(data);
+                    self.last_search = data.search.clone();
+                }
+                let configured = data.font.take();
+                let text = ctx.text();
+                data.font = configured
+                    .iter()
+                    .map(|family| &**family)
+                    .chain(EMOJI_FONTS.iter().copied())
+                    .find(|family| text.font_family(family).is_some())
+                    .map(Into::into);
+                if let Some(configured) = configured.filter(|_| data.font.is_none()) {
+                    eprintln!("emojay: font `{}` is not installed", configured);
+                }
+
+                // A saved position can be left behind on a monitor that has since gone.
+                let window = ctx.window();
+                let far_corner = window.get_position() + window.get_size().to_vec2();
+                let monitors = Screen::get_monitors();
+                let visible = monitors.iter().any(|monitor| {
+                    let area = monitor.virtual_rect();
+                    area.contains(window.get_position()) || area.contains(far_corner)
+                });
+                if data.at_cursor {
+                    move_to_cursor(window);
+                } else if !visible {
+                    if let Some(primary) = monitors.iter().find(|m| m.is_primary()) {
+                        let area = primary.virtual_work_rect();
+                        window.set_position(
+                            area.center() - window.get_size().to_vec2() / 2.0,
+                        );
+                    }
+                }
+                self.list.event(ctx, event, data, env);
+                // Typing can start right away, a summoned window is focused by `show`.
+                ctx.set_focus(self.search_id);
+            }
+            Event::Command(cmd) if cmd.is(SUMMON) => {
+                let mut window = ctx.window().clone();
+                if window.get_window_state() == WindowState::Minimized {
+                    self.show(ctx, data.at_cursor);
+                } else {
+                    window.set_window_state(WindowState::Minimized);
+                }
+                ctx.set_handled();
+            }
+            Event::WindowSize(size) => {
+                // Layout can't change the data, so the columns follow the window instead.
+                self.width = Some(size.width);
+                self.list.event(ctx, event, data, env);
+            }
+            Event::Wheel(wheel) if wheel.mods.ctrl() => {
+                data.zoom(-wheel.wheel_delta.y.signum() * ZOOM_STEP);
+                ctx.set_handled();
+            }
+            // The filters are compared below, like after any other event.
+            Event::Command(cmd) if cmd.is(REFILTER) => ctx.set_handled(),
+            Event::Command(cmd) if cmd.is(SHOW) => {
+                self.show(ctx, data.at_cursor);
+                ctx.set_handled();
+            }
+            #[cfg(not(feature = "paste"))]
+            Event::Command(cmd) if cmd.is(HIDE) => {
+                ctx.window().clone().set_window_state(WindowState::Minimized);
+                ctx.set_handled();
+            }
+            #[cfg(feature = "paste")]
+            Event::Command(cmd) if cmd.is(PASTE) => {
+                // Minimizing hands focus back to the window the picker was raised over.
+                ctx.window().clone().set_window_state(WindowState::Minimized);
+                paste::paste_soon();
+                ctx.set_handled();
+            }
+            // Closing only tucks the picker away while the tray can bring it back.
+            Event::WindowCloseRequested if data.in_tray => {
+                ctx.window().clone().set_window_state(WindowState::Minimized);
+                ctx.set_handled();
+            }
+            _ if !ctx.is_handled() => self.list.event(ctx, event, data, env),
+            _ => {}
+        }
+        if let Some(width) = self.width {
+            data.columns = if width < COMPACT_WIDTH * data.scale {
+                1
+            } else {
+                ((width / (TILE_WIDTH * data.scale)) as usize).max(1)
+            };
+        }
+        if data.category != self.last_category {
+            self.refilter(data);
+            self.last_category = data.category;
+        }
+        // The cached results are of the old versions or hidden emojis, and so are the
+        // ones that would be narrowed, so this starts over from the whole set.
+        let filters = data.filters();
+        if filters != self.last_filters {
+            self.cache = FilterCache::default();
+            self.filtered.0.clear();
+            self.refilter(data);
+            self.last_filters = filters;
+        }
+        if data.search != self.last_search {
+            if data.search.is_empty() {
+                self.refilter(data);
+            } else {
+                // Replacing the token drops any filter still pending for the old text.
+                self.filter_timer = ctx.request_timer(DEBOUNCE);
+            }
+            self.last_search = data.search.clone();
+        }
         ctx.request_paint();
     }
 
@@ -168,6 +1306,22 @@ impl Widget<EmojiStuff> for EmojiPane {
         data: &EmojiStuff,
         env: &Env,
     ) {
+        // The use counts break ties, results ranked before the last copy are out of date.
+        if !old_data.counts.same(&data.counts) {
+            self.cache = FilterCache::default();
+        }
+        // Hiding from the details goes through the delegate, which no event of the grid
+        // follows, and the data can't be filtered from here.
+        if old_data.filters() != data.filters() {
+            ctx.submit_command(REFILTER);
+        }
+        // Restarted by every copy, so the confirmation is for the latest one.
+        if data.copied.is_some() && !old_data.copied.same(&data.copied) {
+            self.flash_timer = ctx.request_timer(FLASH);
+        }
+        if data.copy_error.is_some() && !old_data.copy_error.same(&data.copy_error) {
+            self.error_timer = ctx.request_timer(ERROR_FOR);
+        }
         self.list.update(ctx, old_data, data, env)
     }
 
@@ -186,112 +1340,1390 @@ impl Widget<EmojiStuff> for EmojiPane {
     }
 }
 
-fn emoji_tile(idx: usize) -> Container<[Emoji; 5]> {
-    let painter = Painter::new(|ctx, _, env| {
+/// Toggles whether the emoji in column `.0` is a favorite when its tile is right
+/// clicked, or with Alt held hides it.
+struct PinOnRightClick(usize);
+
+impl<W: Widget<EmojiRow>> Controller<EmojiRow, W> for PinOnRightClick {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut EmojiRow,
+        env: &Env,
+    ) {
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_right() => {
+                if !data.is_padding(self.0) {
+                    let toggle = if mouse.mods.alt() { CONCEAL } else { FAVORITE };
+                    ctx.submit_command(toggle.with(data.emoji(self.0)));
+                }
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+}
+
+/// Scrolls the grid to keep the row of the selected emoji in sight while the selection
+/// moves with the keyboard, and back to where it was when the grid is rebuilt. `.0` is
+/// whether this grid has been scrolled back yet.
+struct GridScroll(bool);
+
+impl<W: Widget<EmojiStuff>> Controller<EmojiStuff, Scroll<EmojiStuff, W>> for GridScroll {
+    fn event(
+        &mut self,
+        child: &mut Scroll<EmojiStuff, W>,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut EmojiStuff,
+        env: &Env,
+    ) {
+        child.event(ctx, event, data, env);
+        if self.0 {
+            data.scroll_offset = child.offset().y;
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        child: &mut Scroll<EmojiStuff, W>,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &EmojiStuff,
+        env: &Env,
+    ) {
+        child.lifecycle(ctx, event, data, env);
+        // Zooming or resizing replaces the grid, it can only scroll once it has a size.
+        if let LifeCycle::Size(_) = event {
+            if !self.0 {
+                self.0 = true;
+                child.scroll_by(Vec2::new(0.0, data.scroll_offset));
+                ctx.request_paint();
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        child: &mut Scroll<EmojiStuff, W>,
+        ctx: &mut UpdateCtx,
+        old_data: &EmojiStuff,
+        data: &EmojiStuff,
+        env: &Env,
+    ) {
+        child.update(ctx, old_data, data, env);
+        // New results start from the top, the same ones keep their place.
+        if !old_data.emojis.same(&data.emojis) && child.scroll_by(-child.offset()) {
+            ctx.request_paint();
+        }
+        if let Some(selected) =
+            data.selected.filter(|_| old_data.selected != data.selected)
+        {
+            // Every row is the same height, so the list's height says where each one is.
+            let rows = data.data_len().max(1);
+            let height = child.child_size().height / rows as f64;
+            let top = (selected / data.columns) as f64 * height;
+            if child.scroll_to(Rect::new(0.0, top, 1.0, top + height)) {
+                ctx.request_paint();
+            }
+        }
+    }
+}
+
+/// Collects the emoji in column `.0` for "Copy all" when its tile is shift clicked.
+struct CollectOnShiftClick(usize);
+
+impl<W: Widget<EmojiRow>> Controller<EmojiRow, W> for CollectOnShiftClick {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut EmojiRow,
+        env: &Env,
+    ) {
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_left() && mouse.mods.shift() => {
+                if !data.is_padding(self.0) {
+                    ctx.submit_command(COLLECT.with(data.emoji(self.0)));
+                }
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+}
+
+/// Copies the emoji in column `.0` when its tile is ctrl or cmd clicked, and then leaves
+/// the picker open if it would close or closes it if it would stay.
+struct CopyOnCtrlClick(usize);
+
+impl<W: Widget<EmojiRow>> Controller<EmojiRow, W> for CopyOnCtrlClick {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut EmojiRow,
+        env: &Env,
+    ) {
+        match event {
+            Event::MouseDown(mouse)
+                if mouse.button.is_left() && (mouse.mods.ctrl() || mouse.mods.meta()) =>
+            {
+                if !data.is_padding(self.0) {
+                    ctx.submit_command(COPY_OTHERWISE.with(data.emoji(self.0)));
+                }
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+}
+
+/// Copies the emoji in column `.0` when its tile is clicked, or opens its details when
+/// it is double clicked. The copy waits out the double click, as it may close the picker
+/// before the second click. `.1` is the wait of the last click.
+struct CopyOrDetailOnClick(usize, TimerToken);
+
+impl<W: Widget<EmojiRow>> Controller<EmojiRow, W> for CopyOrDetailOnClick {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut EmojiRow,
+        env: &Env,
+    ) {
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_left() => {
+                if !data.is_padding(self.0) {
+                    self.1 = if mouse.count >= 2 {
+                        ctx.submit_command(DETAIL.with(data.emoji(self.0)));
+                        TimerToken::INVALID
+                    } else {
+                        ctx.request_timer(DOUBLE_CLICK)
+                    };
+                }
+                ctx.set_handled();
+            }
+            Event::Timer(token) if *token == self.1 => {
+                self.1 = TimerToken::INVALID;
+                if !data.is_padding(self.0) {
+                    ctx.submit_command(COPY.with(data.emoji(self.0)));
+                    ctx.request_paint();
+                }
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+}
+
+/// Reports the emoji in column `.0` as hovered while the mouse is over its tile.
+struct NameOnHover(usize);
+
+impl<W: Widget<EmojiRow>> Controller<EmojiRow, W> for NameOnHover {
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &EmojiRow,
+        env: &Env,
+    ) {
+        if let LifeCycle::HotChanged(hot) = event {
+            let hovered =
+                Some(data.emoji(self.0)).filter(|_| *hot && !data.is_padding(self.0));
+            ctx.submit_command(HOVER.with(hovered));
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
+}
+
+/// How many glyphs a font that doesn't know `glyph` as a whole draws it as, the emojis
+/// of a sequence, the letters of a flag and skin tones each on their own.
+fn drawn_parts(glyph: &str) -> usize {
+    let parts = glyph.split('\u{200D}').map(|part| {
+        let letters = part.chars().filter(|c| ('\u{1F1E6}'..='\u{1F1FF}').contains(c));
+        let tones = part.chars().filter(|c| ('\u{1F3FB}'..='\u{1F3FF}').contains(c));
+        letters.count().max(1) + tones.count()
+    });
+    parts.sum()
+}
+
+/// The size a glyph is drawn at in a tile of `scale`. The UI font draws flags and
+/// sequences as their parts, which are shrunk to fit side by side.
+fn glyph_size(glyph: &str, scale: f64, emoji_font: bool) -> f64 {
+    let parts = if emoji_font { 1 } else { drawn_parts(glyph) };
+    30.0 * scale / parts as f64
+}
+
+/// Sizes the glyph in column `.0` of a tile of scale `.1` by `glyph_size`.
+struct FitGlyph(usize, f64);
+
+impl FitGlyph {
+    fn size(&self, row: &EmojiRow) -> f64 {
+        glyph_size(row.emoji(self.0).glyph, self.1, row.emoji_font)
+    }
+}
+
+impl Controller<EmojiRow, Label<EmojiRow>> for FitGlyph {
+    fn lifecycle(
+        &mut self,
+        child: &mut Label<EmojiRow>,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &EmojiRow,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            child.set_text_size(self.size(data));
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(
+        &mut self,
+        child: &mut Label<EmojiRow>,
+        ctx: &mut druid::UpdateCtx,
+        old_data: &EmojiRow,
+        data: &EmojiRow,
+        env: &Env,
+    ) {
+        let size = self.size(data);
+        if size != self.size(old_data) {
+            child.set_text_size(size);
+            ctx.request_layout();
+        }
+        child.update(ctx, old_data, data, env)
+    }
+}
+
+/// A tile of the grid, or with `named` a line of the single column list that also shows
+/// the emoji's name.
+fn emoji_tile(
+    idx: usize,
+    scale: f64,
+    named: bool,
+    captions: bool,
+) -> Container<EmojiRow> {
+    let painter = Painter::new(move |ctx, row: &EmojiRow, env| {
         let bounds = ctx.size().to_rect();
 
-        ctx.fill(bounds, &env.get(theme::BACKGROUND_DARK));
+        if row.pinned {
+            ctx.fill(bounds, &env.get(theme::PRIMARY_DARK));
+        } else if idx < row.literal {
+            ctx.fill(bounds, &env.get(theme::BACKGROUND_LIGHT));
+        } else {
+            ctx.fill(bounds, &env.get(theme::BACKGROUND_DARK));
+        }
+
+        if row.is_padding(idx) {
+            return;
+        }
 
         if ctx.is_hot() {
-            ctx.stroke(bounds.inset(-0.5), &Color::WHITE, 1.0);
+            ctx.stroke(bounds.inset(-0.5), &env.get(palette::HOVER_STROKE), 1.0);
+        }
+
+        if row.selected == Some(idx) {
+            ctx.stroke(bounds.inset(-1.0), &env.get(palette::HIGHLIGHT), 2.0);
         }
 
-        if ctx.is_active() {
-            ctx.fill(bounds, &env.get(theme::PRIMARY_LIGHT));
+        if ctx.is_active() || row.copied == Some(idx) {
+            ctx.fill(bounds, &env.get(palette::HIGHLIGHT));
         }
     });
 
-    Label::new(move |emojis: &[Emoji; 5], _env: &Env| emojis[idx].0.1.to_owned())
-        .with_text_size(30.0)
-        .center()
-        .align_vertical(UnitPoint::LEFT)
-        .padding(10.0)
-        .expand()
-        .height(46.0)
-        .background(painter)
-}
-
-fn emoji_row() -> Flex<[Emoji; 5]> {
-    fn on_click(moji: &Emoji, ctx: &mut EventCtx) {
-        ctx.submit_command(COPY.with(*moji));
-        ctx.request_paint()
-    }
-    Flex::row()
-        .with_spacer(1.0)
-        .with_flex_child(
-            emoji_tile(0).on_click(move |ctx, data: &mut [Emoji; 5], _env| {
-                on_click(&data[0], ctx)
-            }),
+    let glyph = Label::new(move |row: &EmojiRow, _env: &Env| {
+        if row.is_padding(idx) { "" } else { row.emoji(idx).glyph }.to_owned()
+    })
+    .with_font(palette::EMOJI_FONT)
+    .with_text_size(30.0 * scale)
+    // A flag or joined sequence the font has no single glyph for is drawn as its parts
+    // side by side, which is cut off at the tile rather than drawn over its neighbors.
+    .with_line_break_mode(LineBreaking::Clip)
+    .controller(FitGlyph(idx, scale));
+    let content = if named {
+        Flex::row()
+            .with_child(glyph)
+            .with_spacer(8.0 * scale)
+            .with_flex_child(
+                Label::new(move |row: &EmojiRow, _env: &Env| {
+                    row.emoji(idx).name.to_owned()
+                })
+                .with_text_size(14.0 * scale)
+                .expand_width(),
+                1.0,
+            )
+            .boxed()
+    } else if captions {
+        Flex::column()
+            .with_child(glyph)
+            .with_child(
+                Label::new(move |row: &EmojiRow, _env: &Env| {
+                    if row.is_padding(idx) {
+                        ""
+                    } else {
+                        mojis::shortcode(row.emoji(idx).name)
+                    }
+                    .to_owned()
+                })
+                .with_text_size(9.0 * scale)
+                .with_text_color(theme::PLACEHOLDER_COLOR)
+                .with_line_break_mode(LineBreaking::Clip),
+            )
+            .center()
+            .boxed()
+    } else {
+        glyph.center().align_vertical(UnitPoint::LEFT).boxed()
+    };
+    let height = if captions && !named { 66.0 } else { 46.0 };
+
+    content.padding(10.0 * scale).expand().height(height * scale).background(painter)
+}
+
+/// How the tiles of the grid are laid out, the same for every grid in the picker.
+#[derive(Debug, Clone, Copy)]
+struct Tiles {
+    /// The space left between the tiles of a row.
+    column_gap: f64,
+    /// The space left between rows.
+    row_gap: f64,
+    /// Whether each glyph has its shortcode under it.
+    captions: bool,
+}
+
+fn emoji_row(columns: usize, scale: f64, tiles: Tiles) -> Flex<EmojiRow> {
+    let mut row = Flex::row();
+    for idx in 0..columns {
+        row.add_spacer(tiles.column_gap);
+        row.add_flex_child(
+            emoji_tile(idx, scale, columns == 1, tiles.captions)
+                .controller(CopyOrDetailOnClick(idx, TimerToken::INVALID))
+                .controller(PinOnRightClick(idx))
+                .controller(CollectOnShiftClick(idx))
+                .controller(CopyOnCtrlClick(idx))
+                .controller(NameOnHover(idx)),
             1.0,
-        )
-        .with_spacer(1.0)
-        .with_flex_child(
-            emoji_tile(1).on_click(move |ctx, data: &mut [Emoji; 5], _env| {
-                on_click(&data[1], ctx)
-            }),
+        );
+    }
+    row
+}
+
+fn category_tabs() -> Flex<EmojiStuff> {
+    let mut tabs = Flex::row();
+    for &category in Category::ALL {
+        let painter = Painter::new(move |ctx, data: &EmojiStuff, env| {
+            let bounds = ctx.size().to_rect();
+
+            if data.category == Some(category) {
+                ctx.fill(bounds, &env.get(theme::PRIMARY_DARK));
+            }
+
+            if ctx.is_hot() {
+                ctx.stroke(bounds.inset(-0.5), &env.get(palette::HOVER_STROKE), 1.0);
+            }
+        });
+        tabs.add_flex_child(
+            Label::new(category.icon())
+                .with_font(palette::EMOJI_FONT)
+                .with_text_size(18.0)
+                .center()
+                .padding(2.0)
+                .background(painter)
+                .on_click(move |_ctx, data: &mut EmojiStuff, _env| {
+                    data.category = if data.category == Some(category) {
+                        None
+                    } else {
+                        Some(category)
+                    };
+                }),
             1.0,
+        );
+    }
+    tabs.with_child(since_version())
+}
+
+/// Steps through the Emoji versions of the source on click, showing only the emojis
+/// added since that version, and back to all of them after the newest.
+fn since_version() -> impl Widget<EmojiStuff> {
+    Label::new(|data: &EmojiStuff, _env: &Env| match data.min_version {
+        Some(version) => format!("✨ {:.1}+", version),
+        None => "✨ all".to_owned(),
+    })
+    .padding(2.0)
+    .on_click(|_ctx, data: &mut EmojiStuff, _env| {
+        let versions = || {
+            data.source
+                .iter()
+                .filter(|e| supported(data.max_version)(e))
+                .map(Emoji::version)
+        };
+        // From the oldest version on is every emoji, so the first step is past it.
+        let after = data.min_version.or_else(|| versions().min_by(f64::total_cmp));
+        let after = after.unwrap_or(f64::INFINITY);
+        data.min_version =
+            versions().filter(|&version| version > after).min_by(f64::total_cmp);
+    })
+}
+
+fn swatch(tone: SkinTone) -> Color {
+    match tone {
+        SkinTone::Default => Color::rgb8(0xFF, 0xC8, 0x3D),
+        SkinTone::Light => Color::rgb8(0xF7, 0xDE, 0xCE),
+        SkinTone::MediumLight => Color::rgb8(0xF3, 0xD2, 0xA2),
+        SkinTone::Medium => Color::rgb8(0xD5, 0xAB, 0x88),
+        SkinTone::MediumDark => Color::rgb8(0xAF, 0x7E, 0x57),
+        SkinTone::Dark => Color::rgb8(0x7C, 0x53, 0x3E),
+    }
+}
+
+fn skin_tones() -> Flex<EmojiStuff> {
+    let mut tones = Flex::row();
+    for &tone in SkinTone::ALL {
+        let painter = Painter::new(move |ctx, data: &EmojiStuff, env| {
+            let bounds = ctx.size().to_rect();
+
+            ctx.fill(bounds, &swatch(tone));
+
+            if data.skin_tone == tone {
+                ctx.stroke(bounds.inset(-1.0), &env.get(palette::HOVER_STROKE), 2.0);
+            }
+        });
+        tones.add_spacer(2.0);
+        tones.add_child(
+            painter
+                .fix_size(14.0, 14.0)
+                .on_click(move |_ctx, data: &mut EmojiStuff, _env| data.skin_tone = tone),
+        );
+    }
+    tones
+}
+
+/// A description with the characters `search` matched in bold, followed by `rest`.
+fn highlight(name: &str, rest: &str, search: &str, matcher: Matcher) -> RichText {
+    let mut text = RichText::new(format!("{}{}", name, rest).into());
+    for idx in emojay::matched_indices(name, search, matcher) {
+        text.add_attribute(idx..idx + 1, Attribute::weight(FontWeight::BOLD));
+        text.add_attribute(idx..idx + 1, Attribute::text_color(theme::PRIMARY_LIGHT));
+    }
+    text
+}
+
+/// What the mouse does to a tile besides copying it, shown while no tile is hovered.
+const MOUSE_HINT: &str =
+    "Double click for details, right click to pin, Alt+right click to hide";
+
+/// Names the hovered emoji, or the one Enter would copy followed by what clicking a tile
+/// can do, so it's clear what it is and why it matched the search. Confirms a copy for a
+/// moment after it happens, or says why it failed.
+fn chosen_description() -> impl Widget<EmojiStuff> {
+    RawLabel::new().lens(lens::Map::new(description, |_, _| {}))
+}
+
+fn description(data: &EmojiStuff) -> RichText {
+    if let Some(err) = &data.copy_error {
+        let message = format!("Nothing copied, {}", err);
+        let mut text = RichText::new(message.as_str().into());
+        text.add_attribute(.., Attribute::text_color(Color::rgb8(0xe0, 0x4f, 0x4f)));
+        return text;
+    }
+    if let Some(copied) = data.copied {
+        return RichText::new(format!("Copied {}", copied.glyph).into());
+    }
+    if let Some(hovered) = data.hovered {
+        return highlight(hovered.name, "", &data.search, data.matcher);
+    }
+    let name = data.chosen().map_or("", |emoji| emoji.name);
+    let hint = match name {
+        "" => MOUSE_HINT.to_owned(),
+        _ => format!(" · {}", MOUSE_HINT),
+    };
+    let mut text = highlight(name, &hint, &data.search, data.matcher);
+    text.add_attribute(name.len().., Attribute::text_color(theme::PLACEHOLDER_COLOR));
+    text
+}
+
+/// The shift clicked emojis waiting to be copied together.
+fn collection_bar() -> impl Widget<EmojiStuff> {
+    let collected = Label::new(|data: &EmojiStuff, _env: &Env| {
+        data.collected.iter().map(|emoji| emoji.glyph).collect::<String>()
+    })
+    .with_font(palette::EMOJI_FONT)
+    .with_text_size(18.0);
+    let bar = Flex::row()
+        .with_flex_child(collected.align_left(), 1.0)
+        .with_child(Button::new("Copy all").on_click(|ctx, _data, _env| {
+            ctx.submit_command(COPY_ALL);
+        }))
+        .with_spacer(4.0)
+        .with_child(Button::new("Clear").on_click(|_ctx, data: &mut EmojiStuff, _env| {
+            data.collected.clear();
+        }))
+        .padding(4.0);
+    Either::new(
+        |data: &EmojiStuff, _env| data.collected.is_empty(),
+        SizedBox::empty(),
+        bar,
+    )
+}
+
+/// How many emojis the grid holds, every one of them while the search is empty.
+fn result_count() -> impl Widget<EmojiStuff> {
+    Label::new(|data: &EmojiStuff, _env: &Env| match data.emojis.0.len() {
+        1 => "1 result".to_owned(),
+        len => format!("{} results", len),
+    })
+    .with_text_color(theme::PLACEHOLDER_COLOR)
+}
+
+/// The past searches matching what is typed so far, clicking one searches it again.
+fn search_history() -> impl Widget<EmojiStuff> {
+    List::new(|| {
+        Label::new(|search: &String, _env: &Env| search.clone())
+            .with_text_color(theme::PLACEHOLDER_COLOR)
+            .on_click(|ctx, search: &mut String, _env| {
+                ctx.submit_command(SEARCH_AGAIN.with(search.clone()))
+            })
+            .padding((0.0, 0.0, 8.0, 0.0))
+    })
+    .horizontal()
+    .lens(lens::Map::new(|data: &EmojiStuff| data.history(), |_, _| {}))
+    .align_left()
+}
+
+/// Lets the grid go past the results it is capped at, a few hundred at a time.
+fn show_more() -> impl Widget<EmojiStuff> {
+    let more = |data: &EmojiStuff| (data.emojis.0.len() - data.shown()).min(RESULTS_CAP);
+    Either::new(
+        move |data: &EmojiStuff, _env| more(data) > 0,
+        Button::new(move |data: &EmojiStuff, _env: &Env| {
+            format!("Show {} more", more(data))
+        })
+        .on_click(|_ctx, data: &mut EmojiStuff, _env| data.limit += RESULTS_CAP)
+        .padding(4.0)
+        .center(),
+        SizedBox::empty(),
+    )
+}
+
+/// Shown in place of the grid when nothing matches the search, usually a typo.
+fn no_results() -> impl Widget<EmojiStuff> {
+    let clear = Label::new("Clear the search (Esc)")
+        .with_text_color(theme::PRIMARY_LIGHT)
+        .on_click(|_ctx, data: &mut EmojiStuff, _env| data.search.clear());
+    Flex::column()
+        .with_child(Label::new(|data: &EmojiStuff, _env: &Env| {
+            format!("No emoji found for '{}'", data.search)
+        }))
+        .with_spacer(6.0)
+        .with_child(clear)
+        .center()
+}
+
+/// Everything known about the emoji whose details were opened.
+fn emoji_detail() -> impl Widget<EmojiStuff> {
+    let about = Flex::column()
+        .with_child(
+            Label::new(|emoji: &Emoji, _env: &Env| emoji.glyph.to_owned())
+                .with_font(palette::EMOJI_FONT)
+                .with_text_size(64.0),
         )
-        .with_spacer(1.0)
-        .with_flex_child(
-            emoji_tile(2).on_click(move |ctx, data: &mut [Emoji; 5], _env| {
-                on_click(&data[2], ctx)
-            }),
-            1.0,
+        .with_child(Label::new(|emoji: &Emoji, _env: &Env| emoji.name.to_owned()))
+        .with_child(Label::new(|emoji: &Emoji, _env: &Env| {
+            OutputFormat::Codepoints.apply(*emoji, SkinTone::Default).into_owned()
+        }))
+        .with_child(Label::new(|emoji: &Emoji, _env: &Env| match emoji.subgroup() {
+            "" => emoji.group.name().to_owned(),
+            subgroup => format!("{} / {}", emoji.group.name(), subgroup),
+        }))
+        .with_child(Label::new(|emoji: &Emoji, _env: &Env| {
+            format!("Since Emoji {:.1}", emoji.version())
+        }))
+        .with_child(
+            Label::new(|emoji: &Emoji, _env: &Env| emoji.keywords.join(", "))
+                .with_line_break_mode(LineBreaking::WordWrap),
         )
-        .with_spacer(1.0)
-        .with_flex_child(
-            emoji_tile(3).on_click(move |ctx, data: &mut [Emoji; 5], _env| {
-                on_click(&data[3], ctx)
-            }),
-            1.0,
+        .lens(lens::Map::new(
+            |data: &EmojiStuff| data.detail.unwrap_or(BLANK),
+            |_, _| {},
+        ));
+    let buttons = Flex::row()
+        .with_child(Button::new("Copy codepoints").on_click(
+            |_ctx, data: &mut EmojiStuff, _env| {
+                if let Some(emoji) = data.detail {
+                    let codepoints =
+                        OutputFormat::Codepoints.apply(emoji, data.skin_tone);
+                    if let Err(err) = put_clipboard(codepoints.into_owned()) {
+                        eprintln!("emojay: {}", err);
+                        data.copy_error = Some(err);
+                    }
+                }
+            },
+        ))
+        .with_spacer(4.0)
+        .with_child(Button::new("Hide").on_click(|ctx, data: &mut EmojiStuff, _env| {
+            if let Some(emoji) = data.detail.take() {
+                ctx.submit_command(CONCEAL.with(emoji));
+            }
+        }))
+        .with_spacer(4.0)
+        .with_child(
+            Button::new("Close (Esc)")
+                .on_click(|_ctx, data: &mut EmojiStuff, _env| data.detail = None),
+        );
+    Flex::column()
+        .with_child(about)
+        .with_spacer(8.0)
+        .with_child(buttons)
+        .padding(8.0)
+        .center()
+}
+
+/// The most copied emojis and the copies of the last week, all from what is saved on
+/// this machine.
+fn usage_stats() -> impl Widget<EmojiStuff> {
+    let top = List::new(|| {
+        Flex::row()
+            .with_child(
+                Label::new(|(emoji, _): &(Emoji, u32), _env: &Env| {
+                    emoji.glyph.to_owned()
+                })
+                .with_font(palette::EMOJI_FONT)
+                .with_text_size(24.0),
+            )
+            .with_spacer(8.0)
+            .with_flex_child(
+                Label::new(|(emoji, _): &(Emoji, u32), _env: &Env| emoji.name.to_owned())
+                    .expand_width(),
+                1.0,
+            )
+            .with_child(Label::new(|&(_, n): &(Emoji, u32), _env: &Env| n.to_string()))
+            .padding(2.0)
+    })
+    .lens(lens::Map::new(|data: &EmojiStuff| data.top_copied(), |_, _| {}));
+    let buttons = Flex::row()
+        .with_child(
+            Button::new("Clear stats")
+                .on_click(|_ctx, data: &mut EmojiStuff, _env| data.clear_stats()),
+        )
+        .with_spacer(4.0)
+        .with_child(
+            Button::new("Done")
+                .on_click(|_ctx, data: &mut EmojiStuff, _env| data.showing_stats = false),
+        );
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(|data: &EmojiStuff, _env: &Env| {
+            match data.copies_this_week() {
+                1 => "1 copy this week".to_owned(),
+                copies => format!("{} copies this week", copies),
+            }
+        }))
+        .with_spacer(4.0)
+        .with_flex_child(Scroll::new(top).vertical().expand(), 1.0)
+        .with_child(buttons)
+        .padding(8.0)
+}
+
+/// Opens and closes the usage panel.
+fn stats_toggle() -> impl Widget<EmojiStuff> {
+    Label::new("📊")
+        .with_font(palette::EMOJI_FONT)
+        .on_click(|_ctx, data: &mut EmojiStuff, _env| {
+            data.showing_stats = !data.showing_stats
+        })
+        .padding((0.0, 0.0, 4.0, 0.0))
+}
+
+/// The hidden emojis, clicking one shows it in the results again.
+fn hidden_emojis() -> impl Widget<EmojiStuff> {
+    let list = List::new(|| {
+        Flex::row()
+            .with_child(
+                Label::new(|emoji: &Emoji, _env: &Env| emoji.glyph.to_owned())
+                    .with_font(palette::EMOJI_FONT)
+                    .with_text_size(24.0),
+            )
+            .with_spacer(8.0)
+            .with_child(Label::new(|emoji: &Emoji, _env: &Env| emoji.name.to_owned()))
+            .padding(2.0)
+            .on_click(|ctx, emoji: &mut Emoji, _env| {
+                ctx.submit_command(CONCEAL.with(*emoji))
+            })
+    })
+    .lens(EmojiStuff::hidden);
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(
+            Label::new("Hidden emojis, click one to show it again")
+                .with_text_color(theme::PLACEHOLDER_COLOR),
         )
-        .with_spacer(1.0)
-        .with_flex_child(
-            emoji_tile(4).on_click(move |ctx, data: &mut [Emoji; 5], _env| {
-                on_click(&data[4], ctx)
+        .with_spacer(4.0)
+        .with_flex_child(Scroll::new(list).vertical().expand(), 1.0)
+        .with_child(
+            Button::new("Done").on_click(|_ctx, data: &mut EmojiStuff, _env| {
+                data.showing_hidden = false
             }),
-            1.0,
         )
+        .padding(8.0)
 }
 
-fn ui_builder() -> EmojiPane {
+/// How many emojis are hidden, clicking it lists them. Not shown while none are.
+fn hidden_toggle() -> impl Widget<EmojiStuff> {
+    Either::new(
+        |data: &EmojiStuff, _env| data.hidden.is_empty(),
+        SizedBox::empty(),
+        Label::new(|data: &EmojiStuff, _env: &Env| format!("🙈 {}", data.hidden.len()))
+            .on_click(|_ctx, data: &mut EmojiStuff, _env| {
+                data.showing_hidden = !data.showing_hidden
+            })
+            .padding((0.0, 0.0, 4.0, 0.0)),
+    )
+}
+
+/// Flips between the dark and light palettes.
+fn theme_toggle() -> impl Widget<EmojiStuff> {
+    Label::new(|data: &EmojiStuff, _env: &Env| {
+        if data.is_light() { "🌙" } else { "☀" }.to_owned()
+    })
+    .on_click(|_ctx, data: &mut EmojiStuff, _env| {
+        data.light = Some(!data.is_light());
+        data.save_toggles();
+    })
+}
+
+/// Switches between hiding the picker after every copy and leaving it open to copy more.
+fn stay_open_toggle() -> impl Widget<EmojiStuff> {
+    Label::new(|data: &EmojiStuff, _env: &Env| {
+        if data.stay_open { "📌" } else { "👋" }.to_owned()
+    })
+    .with_font(palette::EMOJI_FONT)
+    .on_click(|_ctx, data: &mut EmojiStuff, _env| {
+        data.stay_open = !data.stay_open;
+        data.save_toggles();
+    })
+}
+
+/// Rebuilds the widget from `build` whenever the number of columns or the zoom changes.
+fn per_grid<W: Widget<EmojiStuff> + 'static>(
+    build: impl Fn(usize, f64) -> W + 'static,
+) -> ViewSwitcher<EmojiStuff, (usize, f64)> {
+    ViewSwitcher::new(
+        |data: &EmojiStuff, _env| (data.columns, data.scale),
+        move |&(columns, scale), _data, _env| Box::new(build(columns, scale)),
+    )
+}
+
+fn ui_builder(tiles: Tiles) -> EmojiPane {
+    let search_id = WidgetId::next();
     // `TextBox` is of type `Widget<String>`
     // via `.lens` we get it to be of type `Widget<MyComplexState>`
     let searchbar = TextBox::new()
         .with_placeholder("Search emoji's")
+        .with_id(search_id)
         .lens(lens::Map::new(
             |e: &EmojiStuff| e.search.clone(),
             |a: &mut EmojiStuff, b: String| a.search = b,
         ))
         .expand_width();
+    let recent = Either::new(
+        |data: &EmojiStuff, _env| data.search.is_empty() && !data.recent.is_empty(),
+        per_grid(move |columns, scale| {
+            emoji_row(columns, scale, tiles)
+                .lens(lens::Map::new(|data: &EmojiStuff| data.recent_row(), |_, _| {}))
+                .padding((0.0, 4.0))
+        }),
+        SizedBox::empty(),
+    );
+    let favorites = per_grid(move |columns, scale| {
+        List::new(move || emoji_row(columns, scale, tiles))
+            .with_spacing(tiles.row_gap)
+            .lens(lens::Map::new(|data: &EmojiStuff| data.favorite_rows(), |_, _| {}))
+    });
     EmojiPane {
         list: Flex::column()
             .main_axis_alignment(MainAxisAlignment::Start)
             .with_flex_spacer(0.1)
+            .with_child(favorites)
+            .with_child(recent)
             .with_flex_child(
-                Flex::row().with_flex_child(searchbar, 1.0).with_spacer(0.1),
+                Flex::row()
+                    .with_flex_child(searchbar, 1.0)
+                    .with_spacer(4.0)
+                    .with_child(result_count())
+                    .with_child(skin_tones())
+                    .with_spacer(0.1),
                 1.0,
             )
+            .with_child(search_history())
             .with_flex_spacer(0.1)
+            .with_child(category_tabs())
             .main_axis_alignment(MainAxisAlignment::Start)
             .with_flex_child(
-                Scroll::new(List::new(emoji_row).with_spacing(0.4))
-                    .content_must_fill(true)
-                    .vertical()
-                    .lens(EmojiStuff::emojis),
+                Either::new(
+                    |data: &EmojiStuff, _env| data.showing_stats,
+                    usage_stats(),
+                    Either::new(
+                        |data: &EmojiStuff, _env| data.showing_hidden,
+                        hidden_emojis(),
+                        Either::new(
+                            |data: &EmojiStuff, _env| data.detail.is_some(),
+                            emoji_detail(),
+                            Either::new(
+                                |data: &EmojiStuff, _env| data.emojis.0.is_empty(),
+                                no_results(),
+                                per_grid(move |columns, scale| {
+                                    Scroll::new(
+                                        List::new(move || {
+                                            emoji_row(columns, scale, tiles)
+                                        })
+                                        .with_spacing(tiles.row_gap),
+                                    )
+                                    .content_must_fill(true)
+                                    .vertical()
+                                    .controller(GridScroll(false))
+                                }),
+                            ),
+                        ),
+                    ),
+                ),
                 8.0,
+            )
+            .with_child(show_more())
+            .with_child(collection_bar())
+            .with_child(
+                Flex::row()
+                    .with_flex_child(chosen_description().align_left(), 1.0)
+                    .with_child(
+                        Button::new(|data: &EmojiStuff, _env: &Env| {
+                            format!("Copy as {}", data.format.label())
+                        })
+                        .on_click(|_ctx, data: &mut EmojiStuff, _env| data.next_format()),
+                    )
+                    .with_spacer(4.0)
+                    .with_child(
+                        Button::from_label(
+                            Label::new("🎲").with_font(palette::EMOJI_FONT),
+                        )
+                        .on_click(
+                            |ctx, data: &mut EmojiStuff, _env| {
+                                if let Some(emoji) = data.random() {
+                                    ctx.submit_command(COPY.with(emoji));
+                                }
+                            },
+                        ),
+                    )
+                    .with_spacer(4.0)
+                    .with_child(stats_toggle())
+                    .with_child(hidden_toggle())
+                    .with_child(stay_open_toggle())
+                    .with_spacer(4.0)
+                    .with_child(theme_toggle())
+                    .padding(4.0),
             ),
+        last_search: String::new(),
+        last_category: None,
+        last_filters: (None, 0),
+        filter_timer: TimerToken::INVALID,
+        flash_timer: TimerToken::INVALID,
+        error_timer: TimerToken::INVALID,
+        cache: FilterCache::default(),
+        filtered: (String::new(), None),
+        search_id,
+        width: None,
+    }
+}
+
+/// Moves the window's corner to the mouse cursor, keeping it inside the work area of the
+/// monitor the cursor is on.
+fn move_to_cursor(window: &WindowHandle) {
+    let (x, y) = match Mouse::get_mouse_position() {
+        Mouse::Position { x, y } => (x, y),
+        Mouse::Error => return,
+    };
+    // The cursor is reported in pixels, the window is placed in display points.
+    let (x, y) = match window.get_scale() {
+        Ok(scale) => scale.px_to_dp_xy(x, y),
+        Err(_) => (x.into(), y.into()),
+    };
+    let cursor = Point::new(x, y);
+    let size = window.get_size();
+    let area = Screen::get_monitors()
+        .iter()
+        .map(|monitor| monitor.virtual_work_rect())
+        .find(|area| area.contains(cursor));
+    let position = match area {
+        Some(area) => Point::new(
+            cursor.x.min(area.x1 - size.width).max(area.x0),
+            cursor.y.min(area.y1 - size.height).max(area.y0),
+        ),
+        None => cursor,
+    };
+    window.set_position(position);
+}
+
+/// The glyph of `emoji` in the configured default skin tone, for copying from the command
+/// line.
+fn toned(emoji: Emoji) -> String {
+    mojis::with_skin_tone(emoji.glyph, persist::Config::load().default_tone).into_owned()
+}
+
+/// Puts `text` on the clipboard without the picker.
+///
+/// The clipboard belongs to the `Application`, which only exists once launched, so a tiny
+/// undecorated window is opened and the app quits as soon as it appears. On X11 and
+/// Wayland the copy only outlives us if a clipboard manager picks it up.
+fn copy_headless(text: String) {
+    let window = WindowDesc::new(SizedBox::empty())
+        .window_size((1.0, 1.0))
+        .show_titlebar(false)
+        .resizable(false);
+    AppLauncher::with_window(window)
+        .delegate(CopyAndQuit(text))
+        .launch(())
+        .expect("launch failed");
+}
+
+/// Puts `text` on the clipboard in a way that outlives the picker.
+///
+/// druid doesn't report a failed copy, so the clipboard is read back to find out. Without
+/// a clipboard at all the platform code may panic, which is caught and reported too.
+fn put_clipboard(text: String) -> Result<(), String> {
+    #[cfg(target_os = "linux")]
+    if wayland::copy(&text) {
+        return Ok(());
+    }
+    let copied = panic::catch_unwind(|| {
+        let mut clipboard = Application::global().clipboard();
+        clipboard.put_string(&text);
+        clipboard.get_string()
+    });
+    match copied {
+        Ok(Some(copied)) if copied == text => Ok(()),
+        Ok(_) => Err("the clipboard didn't take it".to_owned()),
+        Err(_) => Err("there is no clipboard".to_owned()),
+    }
+}
+
+/// Copies `.0` and quits once there is an `Application` to reach the clipboard through.
+struct CopyAndQuit(String);
+
+impl AppDelegate<()> for CopyAndQuit {
+    fn window_added(
+        &mut self,
+        _id: WindowId,
+        _data: &mut (),
+        _env: &Env,
+        ctx: &mut DelegateCtx,
+    ) {
+        if let Err(err) = put_clipboard(self.0.clone()) {
+            eprintln!("emojay: nothing copied, {}", err);
+            process::exit(1);
+        }
+        println!("{}", self.0);
+        ctx.submit_command(commands::QUIT_APP);
     }
 }
 
+fn columns(config: &persist::Config) -> usize { config.columns.max(1) }
+
+/// The config with the columns and fuzzy match score to beat from `EMOJAY_COLUMNS` and
+/// `EMOJAY_THRESHOLD` over its own, where they are set.
+fn with_env(config: persist::Config) -> persist::Config {
+    let var = |name| env::var(name).ok();
+    persist::Config {
+        columns: var("EMOJAY_COLUMNS")
+            .and_then(|columns| columns.parse().ok())
+            .filter(|&columns| columns > 0)
+            .unwrap_or(config.columns),
+        threshold: var("EMOJAY_THRESHOLD")
+            .and_then(|threshold| threshold.parse().ok())
+            .unwrap_or(config.threshold),
+        ..config
+    }
+}
+
+fn scale(config: &persist::Config) -> f64 { config.scale.clamp(MIN_SCALE, MAX_SCALE) }
+
+/// The size the window opens at, as wide as `columns` need and as tall as it was left.
+/// The columns would never change again if the width it was left at came back.
+fn window_size(
+    columns: usize,
+    scale: f64,
+    saved: Option<persist::Geometry>,
+) -> (f64, f64) {
+    let width = columns as f64 * TILE_WIDTH * scale + 8.0;
+    (width, saved.map_or(324.0, |geometry| geometry.height))
+}
+
+/// Seconds since the Unix epoch, 0 if the clock is set before it.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+/// Whether stdout goes to another program or a file, as with `EMOJI=$(emojay)`. Not
+/// being a terminal isn't enough, launchers and window manager bindings start the picker
+/// with stdout on `/dev/null` or the journal's socket.
+#[cfg(unix)]
+fn stdout_is_piped() -> bool {
+    use std::os::{fd::AsFd, unix::fs::FileTypeExt};
+
+    io::stdout()
+        .as_fd()
+        .try_clone_to_owned()
+        .and_then(|fd| std::fs::File::from(fd).metadata())
+        .is_ok_and(|meta| meta.file_type().is_fifo() || meta.is_file())
+}
+
+#[cfg(not(unix))]
+fn stdout_is_piped() -> bool { false }
+
 fn main() {
-    let main_window = WindowDesc::new(ui_builder())
-        .window_size((298.0, 324.0))
+    let args = cli::Args::parse().unwrap_or_else(|err| {
+        eprintln!("emojay: {}\n\n{}", err, cli::USAGE);
+        process::exit(2);
+    });
+    if args.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+    if args.version {
+        println!("emojay {}", env!("CARGO_PKG_VERSION"));
+        return;
+    }
+    if let (Some(query), true) = (&args.search, args.print) {
+        match best_match(query) {
+            Some(emoji) => println!("{}", toned(emoji)),
+            None => {
+                eprintln!("emojay: no emoji matches `{}`", query);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+    if args.reset_stats {
+        let mut saved = persist::Saved::load();
+        saved.counts.clear();
+        saved.copied_at.clear();
+        if let Err(err) = saved.save() {
+            eprintln!("emojay: failed to reset the stats, {}", err);
+            process::exit(1);
+        }
+        return;
+    }
+    if let Some(query) = &args.copy {
+        match best_match(query) {
+            Some(emoji) => copy_headless(toned(emoji)),
+            None => {
+                eprintln!("emojay: no emoji matches `{}`", query);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    #[cfg(feature = "hotkey")]
+    let hotkey = hotkey::Hotkey::register()
+        .map_err(|err| eprintln!("emojay: no global hotkey, {}", err))
+        .ok();
+
+    let saved = persist::Saved::load();
+    let config = with_env(persist::Config::load().with_toggles(&saved.toggles));
+    let (columns, scale) = (columns(&config), scale(&config));
+
+    let accents = palette::Accents::new(
+        config.accent.as_deref(),
+        config.highlight.as_deref(),
+        config.hover.as_deref(),
+    );
+    let tiles = Tiles {
+        column_gap: config.column_gap.max(0.0),
+        row_gap: config.row_gap.max(0.0),
+        captions: config.captions,
+    };
+    let root = ui_builder(tiles).background(theme::WINDOW_BACKGROUND_COLOR).env_scope(
+        move |env, data: &EmojiStuff| {
+            palette::apply(env, data.is_light());
+            accents.apply(env);
+            palette::set_emoji_font(env, data.font.as_deref());
+        },
+    );
+    let mut main_window = WindowDesc::new(root)
+        .window_size(window_size(columns, scale, saved.window))
         .title(LocalizedString::new("emoji-picker").with_placeholder("Emoji Picker"));
+    // With a hotkey to summon it the picker stays out of the way until it's wanted.
+    #[cfg(feature = "hotkey")]
+    if hotkey.is_some() {
+        main_window = main_window.set_window_state(WindowState::Minimized);
+    }
+    if let Some(geometry) = saved.window {
+        main_window = main_window.set_position((geometry.x, geometry.y));
+    }
 
-    let data = EmojiStuff { search: "".into(), emojis: EmojiList::new(mojis::EMOJIS) };
-    AppLauncher::with_window(main_window)
-        .delegate(EmojiCopy)
-        .launch(data)
-        .expect("launch failed");
+    let data = EmojiStuff::started(mojis::EMOJIS, &config, saved);
+    // `EMOJI=$(emojay)` gets the picked emoji instead of the clipboard.
+    let to = if args.tty {
+        if !cfg!(all(feature = "tty", unix)) {
+            eprintln!("emojay: built without `--tty` support, copying instead");
+        }
+        Destination::Terminal
+    } else if stdout_is_piped() {
+        Destination::Stdout
+    } else {
+        Destination::Clipboard
+    };
+    let launcher = AppLauncher::with_window(main_window).delegate(EmojiCopy::new(to));
+    #[cfg(feature = "hotkey")]
+    if let Some(hotkey) = &hotkey {
+        hotkey.forward(launcher.get_external_handle());
+    }
+    launcher.launch(data).expect("launch failed");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stuff(config: &persist::Config) -> EmojiStuff {
+        EmojiStuff::new(mojis::EMOJIS, config, persist::Saved::default())
+    }
+
+    fn names(list: &EmojiList) -> Vec<&'static str> {
+        list.0.iter().map(|e| e.name).collect()
+    }
+
+    /// Types `search` a letter at a time, filtering each time from the last results the
+    /// way the picker does.
+    fn typed(data: &mut EmojiStuff, search: &str) -> EmojiList {
+        let mut list = data.emojis.clone();
+        for end in 1..=search.len() {
+            let previous = data.search.clone();
+            data.search = search[..end].to_owned();
+            list = list.filter(Some(&previous), data);
+        }
+        list
+    }
+
+    #[test]
+    fn unknown_emojis_are_dropped_on_load() {
+        let known = |names: &[&str]| names.iter().map(ToString::to_string).collect();
+        let saved = persist::Saved {
+            recent: known(&["no_such_emoji", "rocket"]),
+            favorites: known(&["rocket", "no_such_emoji"]),
+            hidden: known(&["no_such_emoji"]),
+            counts: [("no_such_emoji".to_owned(), 3), ("rocket".to_owned(), 1)].into(),
+            ..Default::default()
+        };
+        let data = EmojiStuff::new(mojis::EMOJIS, &persist::Config::default(), saved);
+        let names =
+            |emojis: &Vector<Emoji>| emojis.iter().map(|e| e.name).collect::<Vec<_>>();
+        assert_eq!(names(&data.recent), ["rocket"]);
+        assert_eq!(names(&data.favorites), ["rocket"]);
+        assert!(data.hidden.is_empty());
+        assert_eq!(data.counts.keys().copied().collect::<Vec<_>>(), ["rocket"]);
+    }
+
+    #[test]
+    fn the_state_comes_from_the_config_alone() {
+        let config = persist::Config { columns: 3, threshold: 7, ..Default::default() };
+        let data = stuff(&config);
+        assert_eq!((data.columns, data.threshold), (3, 7));
+        assert!(data.search.is_empty() && !data.system_light);
+    }
+
+    #[test]
+    fn recents_outlast_a_narrow_window() {
+        let config = persist::Config { columns: 1, ..Default::default() };
+        let mut data = stuff(&config);
+        for emoji in &mojis::EMOJIS[..RECENTS + 2] {
+            data.push_recent(*emoji);
+        }
+        assert_eq!(data.recent.len(), RECENTS);
+        assert_eq!(
+            data.recent.front().map(|e| e.name),
+            Some(mojis::EMOJIS[RECENTS + 1].name)
+        );
+        assert_eq!(data.recent_row().chunk().len(), 1);
+        // Widening the window shows more of them again.
+        data.columns = 4;
+        assert_eq!(data.recent_row().chunk().len(), 4);
+    }
+
+    #[test]
+    fn typing_forward_narrows_the_last_results() {
+        let mut data = stuff(&persist::Config::default());
+        let (mut typed, mut previous) = (data.emojis.clone(), "");
+        for end in 1..="rock".len() {
+            data.search = "rock"[..end].to_owned();
+            typed = typed.filter(Some(previous), &data);
+            previous = &"rock"[..end];
+        }
+        let fresh = names(&data.emojis.filter(None, &data));
+        assert_eq!(names(&typed).first(), Some(&"rocket"));
+        assert!(names(&typed).iter().all(|name| fresh.contains(name)));
+
+        // Only what "ro" found is looked through for "roc"...
+        let rocket = EmojiList(Arc::new([emojay::find_emoji("rocket").unwrap()]), 1);
+        data.search = "roc".to_owned();
+        assert_eq!(names(&rocket.filter(Some("ro"), &data)), ["rocket"]);
+        // ...but taking a letter back starts over.
+        data.search = "ro".to_owned();
+        assert!(rocket.filter(Some("roc"), &data).0.len() > 1);
+    }
+
+    #[test]
+    fn typos_are_forgiven_letter_by_letter() {
+        let config = persist::Config { typos: true, ..Default::default() };
+        let mut data = stuff(&config);
+        // "recylc" finds nothing, "recylce" mustn't be looked for in that.
+        assert_eq!(
+            typed(&mut data, "recylce").0.first().map(|e| e.name),
+            Some("recycle")
+        );
+        let mut data = stuff(&config);
+        assert_eq!(typed(&mut data, "rocekt").0.first().map(|e| e.name), Some("rocket"));
+    }
+
+    #[test]
+    fn the_columns_outlast_a_saved_width() {
+        let left = persist::Geometry { x: 10.0, y: 20.0, height: 500.0 };
+        let (width, height) = window_size(3, 1.0, Some(left));
+        assert_eq!(width, 3.0 * TILE_WIDTH + 8.0);
+        assert_eq!(width, window_size(3, 1.0, None).0);
+        assert_eq!(height, left.height);
+    }
+
+    #[test]
+    fn synonyms_keep_to_the_chosen_versions() {
+        let mut config = persist::Config::default();
+        config.synonyms.insert("ship".to_owned(), vec!["rocket".to_owned()]);
+        let mut data = stuff(&config);
+        data.search = "ship".to_owned();
+        let found = |data: &EmojiStuff| {
+            data.emojis.filter(None, data).0.iter().any(|e| e.name == "rocket")
+        };
+        assert!(found(&data));
+        data.min_version = Some(11.0);
+        assert!(!found(&data));
+    }
+
+    #[test]
+    fn synonyms_leave_out_emojis_too_new_to_show() {
+        let mut config = persist::Config::default();
+        config.synonyms.insert("arr".to_owned(), vec!["pirate_flag".to_owned()]);
+        assert_eq!(synonyms(&config, mojis::EMOJIS)["arr"].len(), 1);
+        config.max_version = Some(5.0);
+        assert!(synonyms(&config, mojis::EMOJIS)["arr"].is_empty());
+    }
+
+    #[test]
+    fn pasted_emojis_are_found_in_any_tone() {
+        let mut data = stuff(&persist::Config::default());
+        for (glyph, name) in [("👍🏽", "+1"), ("\u{2764}", "heart"), ("🚀", "rocket")]
+        {
+            data.search = glyph.to_owned();
+            let found = data.emojis.filter(None, &data);
+            assert_eq!(found.0.first().map(|e| e.name), Some(name), "{}", glyph);
+            assert_eq!(found.1, 1, "{}", glyph);
+        }
+        assert!(pasted(mojis::EMOJIS, "\u{1F3FD}").is_none());
+    }
+
+    #[test]
+    fn the_last_row_is_padded_with_nothing() {
+        let mut data = stuff(&persist::Config { columns: 4, ..Default::default() });
+        data.search = "rocket".to_owned();
+        data.emojis = data.emojis.filter(None, &data);
+        let last = data.row(data.emojis.0.len() / 4);
+        let padding =
+            (0..4).filter(|&column| last.is_padding(column)).collect::<Vec<_>>();
+        assert!(!padding.is_empty());
+        for column in padding {
+            assert_eq!((last.emoji(column).name, last.emoji(column).glyph), ("", ""));
+        }
+    }
+
+    #[test]
+    fn only_real_hits_of_a_codepoint_count_as_literal() {
+        let mut data = stuff(&persist::Config::default());
+        data.search = "1f680".to_owned();
+        let found = data.emojis.filter(None, &data);
+        assert_eq!(found.0.first().map(|e| e.name), Some("rocket"));
+        assert!(found.0.iter().all(|e| e.glyph.contains('\u{1F680}')));
+        assert_eq!(found.1, found.0.len());
+        // No glyph has U+C123, so it's searched as text and only fuzzily found.
+        data.search = "c123".to_owned();
+        let found = data.emojis.filter(None, &data);
+        assert!(found.0.iter().any(|e| e.name == "clock1230"));
+        assert_eq!(found.1, 0);
+    }
+
+    #[test]
+    fn hex_looking_words_rank_as_words() {
+        let mut data = stuff(&persist::Config::default());
+        // "cafe" has no digit so isn't read as U+CAFE, "café" is a word of ☕.
+        data.search = "cafe".to_owned();
+        let found = data.emojis.filter(None, &data);
+        assert_eq!(found.0.first().map(|e| e.name), Some("coffee"));
+        assert!(found.0.len() > 1);
+        assert_eq!(found.1, 1);
+        // Read as a codepoint the glyph that is it comes first.
+        data.search = "2615".to_owned();
+        let found = data.emojis.filter(None, &data);
+        assert_eq!((found.0.first().map(|e| e.name), found.1), (Some("coffee"), 1));
+    }
+
+    #[test]
+    fn flags_and_sequences_fit_their_tile_without_an_emoji_font() {
+        for (glyph, parts) in [
+            ("🚀", 1),
+            ("🇨🇼", 2),
+            ("👩\u{200D}💻", 2),
+            ("👨\u{200D}👩\u{200D}👧\u{200D}👦", 4),
+            ("👍🏽", 2),
+            ("🏳\u{FE0F}\u{200D}🌈", 2),
+        ] {
+            assert_eq!(drawn_parts(glyph), parts, "{}", glyph);
+            assert_eq!(glyph_size(glyph, 1.0, false), 30.0 / parts as f64, "{}", glyph);
+            // An emoji font draws every one of them as a single glyph.
+            assert_eq!(glyph_size(glyph, 2.0, true), 60.0, "{}", glyph);
+        }
+    }
+
+    #[test]
+    fn hidden_emojis_leave_the_results() {
+        let mut data = stuff(&persist::Config::default());
+        let rocket = emojay::find_emoji("rocket").unwrap();
+        let filters = data.filters();
+        data.toggle_hidden(rocket);
+        // The grid filters again on its next event whenever these change.
+        assert_ne!(data.filters(), filters);
+        data.search = "rocket".to_owned();
+        let found = data.emojis.filter(None, &data);
+        assert!(found.0.iter().all(|e| e.name != "rocket"));
+        data.toggle_hidden(rocket);
+        let found = data.emojis.filter(None, &data);
+        assert_eq!(found.0.first().map(|e| e.name), Some("rocket"));
+    }
+
+    #[test]
+    fn the_mouse_hint_shows_until_a_tile_is_hovered() {
+        use druid::piet::TextStorage as _;
+        let mut data = stuff(&persist::Config::default());
+        data.search = "rocket".to_owned();
+        data.emojis = data.emojis.filter(None, &data);
+        assert!(data.chosen().is_some());
+        let shown = description(&data);
+        assert!(shown.as_str().starts_with("rocket · "));
+        assert!(shown.as_str().ends_with(MOUSE_HINT));
+        data.hovered = data.chosen();
+        assert_eq!(description(&data).as_str(), "rocket");
+    }
 }
@@ -1,1572 +1,3659 @@
-pub static EMOJIS: &[(&str, &str)] = &[
-    ("grinning", "😀"),
-    ("grimacing", "😬"),
-    ("grin", "😁"),
-    ("joy", "😂"),
-    ("rofl", "🤣"),
-    ("partying", "🥳"),
-    ("smiley", "😃"),
-    ("smile", "😄"),
-    ("sweat_smile", "😅"),
-    ("laughing", "😆"),
-    ("innocent", "😇"),
-    ("wink", "😉"),
-    ("blush", "😊"),
-    ("slightly_smiling_face", "🙂"),
-    ("upside_down_face", "🙃"),
-    ("relaxed", "☺️"),
-    ("yum", "😋"),
-    ("relieved", "😌"),
-    ("heart_eyes", "😍"),
-    ("smiling_face_with_three_hearts", "🥰"),
-    ("kissing_heart", "😘"),
-    ("kissing", "😗"),
-    ("kissing_smiling_eyes", "😙"),
-    ("kissing_closed_eyes", "😚"),
-    ("stuck_out_tongue_winking_eye", "😜"),
-    ("zany", "🤪"),
-    ("raised_eyebrow", "🤨"),
-    ("monocle", "🧐"),
-    ("stuck_out_tongue_closed_eyes", "😝"),
-    ("stuck_out_tongue", "😛"),
-    ("money_mouth_face", "🤑"),
-    ("nerd_face", "🤓"),
-    ("sunglasses", "😎"),
-    ("star_struck", "🤩"),
-    ("clown_face", "🤡"),
-    ("cowboy_hat_face", "🤠"),
-    ("hugs", "🤗"),
-    ("smirk", "😏"),
-    ("no_mouth", "😶"),
-    ("neutral_face", "😐"),
-    ("expressionless", "😑"),
-    ("unamused", "😒"),
-    ("roll_eyes", "🙄"),
-    ("thinking", "🤔"),
-    ("lying_face", "🤥"),
-    ("hand_over_mouth", "🤭"),
-    ("shushing", "🤫"),
-    ("symbols_over_mouth", "🤬"),
-    ("exploding_head", "🤯"),
-    ("flushed", "😳"),
-    ("disappointed", "😞"),
-    ("worried", "😟"),
-    ("angry", "😠"),
-    ("rage", "😡"),
-    ("pensive", "😔"),
-    ("confused", "😕"),
-    ("slightly_frowning_face", "🙁"),
-    ("frowning_face", "☹"),
-    ("persevere", "😣"),
-    ("confounded", "😖"),
-    ("tired_face", "😫"),
-    ("weary", "😩"),
-    ("pleading", "🥺"),
-    ("triumph", "😤"),
-    ("open_mouth", "😮"),
-    ("scream", "😱"),
-    ("fearful", "😨"),
-    ("cold_sweat", "😰"),
-    ("hushed", "😯"),
-    ("frowning", "😦"),
-    ("anguished", "😧"),
-    ("cry", "😢"),
-    ("disappointed_relieved", "😥"),
-    ("drooling_face", "🤤"),
-    ("sleepy", "😪"),
-    ("sweat", "😓"),
-    ("hot", "🥵"),
-    ("cold", "🥶"),
-    ("sob", "😭"),
-    ("dizzy_face", "😵"),
-    ("astonished", "😲"),
-    ("zipper_mouth_face", "🤐"),
-    ("nauseated_face", "🤢"),
-    ("sneezing_face", "🤧"),
-    ("vomiting", "🤮"),
-    ("mask", "😷"),
-    ("face_with_thermometer", "🤒"),
-    ("face_with_head_bandage", "🤕"),
-    ("woozy", "🥴"),
-    ("sleeping", "😴"),
-    ("zzz", "💤"),
-    ("poop", "💩"),
-    ("smiling_imp", "😈"),
-    ("imp", "👿"),
-    ("japanese_ogre", "👹"),
-    ("japanese_goblin", "👺"),
-    ("skull", "💀"),
-    ("ghost", "👻"),
-    ("alien", "👽"),
-    ("robot", "🤖"),
-    ("smiley_cat", "😺"),
-    ("smile_cat", "😸"),
-    ("joy_cat", "😹"),
-    ("heart_eyes_cat", "😻"),
-    ("smirk_cat", "😼"),
-    ("kissing_cat", "😽"),
-    ("scream_cat", "🙀"),
-    ("crying_cat_face", "😿"),
-    ("pouting_cat", "😾"),
-    ("palms_up", "🤲"),
-    ("raised_hands", "🙌"),
-    ("clap", "👏"),
-    ("wave", "👋"),
-    ("call_me_hand", "🤙"),
-    ("+1", "👍"),
-    ("-1", "👎"),
-    ("facepunch", "👊"),
-    ("fist", "✊"),
-    ("fist_left", "🤛"),
-    ("fist_right", "🤜"),
-    ("v", "✌"),
-    ("ok_hand", "👌"),
-    ("raised_hand", "✋"),
-    ("raised_back_of_hand", "🤚"),
-    ("open_hands", "👐"),
-    ("muscle", "💪"),
-    ("pray", "🙏"),
-    ("foot", "🦶"),
-    ("leg", "🦵"),
-    ("handshake", "🤝"),
-    ("point_up", "☝"),
-    ("point_up_2", "👆"),
-    ("point_down", "👇"),
-    ("point_left", "👈"),
-    ("point_right", "👉"),
-    ("fu", "🖕"),
-    ("raised_hand_with_fingers_splayed", "🖐"),
-    ("love_you", "🤟"),
-    ("metal", "🤘"),
-    ("crossed_fingers", "🤞"),
-    ("vulcan_salute", "🖖"),
-    ("writing_hand", "✍"),
-    ("selfie", "🤳"),
-    ("nail_care", "💅"),
-    ("lips", "👄"),
-    ("tooth", "🦷"),
-    ("tongue", "👅"),
-    ("ear", "👂"),
-    ("nose", "👃"),
-    ("eye", "👁"),
-    ("eyes", "👀"),
-    ("brain", "🧠"),
-    ("bust_in_silhouette", "👤"),
-    ("busts_in_silhouette", "👥"),
-    ("speaking_head", "🗣"),
-    ("baby", "👶"),
-    ("child", "🧒"),
-    ("boy", "👦"),
-    ("girl", "👧"),
-    ("adult", "🧑"),
-    ("man", "👨"),
-    ("woman", "👩"),
-    ("blonde_woman", "👱‍♀️"),
-    ("blonde_man", "👱"),
-    ("bearded_person", "🧔"),
-    ("older_adult", "🧓"),
-    ("older_man", "👴"),
-    ("older_woman", "👵"),
-    ("man_with_gua_pi_mao", "👲"),
-    ("woman_with_headscarf", "🧕"),
-    ("woman_with_turban", "👳‍♀️"),
-    ("man_with_turban", "👳"),
-    ("policewoman", "👮‍♀️"),
-    ("policeman", "👮"),
-    ("construction_worker_woman", "👷‍♀️"),
-    ("construction_worker_man", "👷"),
-    ("guardswoman", "💂‍♀️"),
-    ("guardsman", "💂"),
-    ("female_detective", "🕵️‍♀️"),
-    ("male_detective", "🕵"),
-    ("woman_health_worker", "👩‍⚕️"),
-    ("man_health_worker", "👨‍⚕️"),
-    ("woman_farmer", "👩‍🌾"),
-    ("man_farmer", "👨‍🌾"),
-    ("woman_cook", "👩‍🍳"),
-    ("man_cook", "👨‍🍳"),
-    ("woman_student", "👩‍🎓"),
-    ("man_student", "👨‍🎓"),
-    ("woman_singer", "👩‍🎤"),
-    ("man_singer", "👨‍🎤"),
-    ("woman_teacher", "👩‍🏫"),
-    ("man_teacher", "👨‍🏫"),
-    ("woman_factory_worker", "👩‍🏭"),
-    ("man_factory_worker", "👨‍🏭"),
-    ("woman_technologist", "👩‍💻"),
-    ("man_technologist", "👨‍💻"),
-    ("woman_office_worker", "👩‍💼"),
-    ("man_office_worker", "👨‍💼"),
-    ("woman_mechanic", "👩‍🔧"),
-    ("man_mechanic", "👨‍🔧"),
-    ("woman_scientist", "👩‍🔬"),
-    ("man_scientist", "👨‍🔬"),
-    ("woman_artist", "👩‍🎨"),
-    ("man_artist", "👨‍🎨"),
-    ("woman_firefighter", "👩‍🚒"),
-    ("man_firefighter", "👨‍🚒"),
-    ("woman_pilot", "👩‍✈️"),
-    ("man_pilot", "👨‍✈️"),
-    ("woman_astronaut", "👩‍🚀"),
-    ("man_astronaut", "👨‍🚀"),
-    ("woman_judge", "👩‍⚖️"),
-    ("man_judge", "👨‍⚖️"),
-    ("woman_superhero", "🦸‍♀️"),
-    ("man_superhero", "🦸‍♂️"),
-    ("woman_supervillain", "🦹‍♀️"),
-    ("man_supervillain", "🦹‍♂️"),
-    ("mrs_claus", "🤶"),
-    ("santa", "🎅"),
-    ("sorceress", "🧙‍♀️"),
-    ("wizard", "🧙‍♂️"),
-    ("woman_elf", "🧝‍♀️"),
-    ("man_elf", "🧝‍♂️"),
-    ("woman_vampire", "🧛‍♀️"),
-    ("man_vampire", "🧛‍♂️"),
-    ("woman_zombie", "🧟‍♀️"),
-    ("man_zombie", "🧟‍♂️"),
-    ("woman_genie", "🧞‍♀️"),
-    ("man_genie", "🧞‍♂️"),
-    ("mermaid", "🧜‍♀️"),
-    ("merman", "🧜‍♂️"),
-    ("woman_fairy", "🧚‍♀️"),
-    ("man_fairy", "🧚‍♂️"),
-    ("angel", "👼"),
-    ("pregnant_woman", "🤰"),
-    ("breastfeeding", "🤱"),
-    ("princess", "👸"),
-    ("prince", "🤴"),
-    ("bride_with_veil", "👰"),
-    ("man_in_tuxedo", "🤵"),
-    ("running_woman", "🏃‍♀️"),
-    ("running_man", "🏃"),
-    ("walking_woman", "🚶‍♀️"),
-    ("walking_man", "🚶"),
-    ("dancer", "💃"),
-    ("man_dancing", "🕺"),
-    ("dancing_women", "👯"),
-    ("dancing_men", "👯‍♂️"),
-    ("couple", "👫"),
-    ("two_men_holding_hands", "👬"),
-    ("two_women_holding_hands", "👭"),
-    ("bowing_woman", "🙇‍♀️"),
-    ("bowing_man", "🙇"),
-    ("man_facepalming", "🤦‍♂️"),
-    ("woman_facepalming", "🤦‍♀️"),
-    ("woman_shrugging", "🤷"),
-    ("man_shrugging", "🤷‍♂️"),
-    ("tipping_hand_woman", "💁"),
-    ("tipping_hand_man", "💁‍♂️"),
-    ("no_good_woman", "🙅"),
-    ("no_good_man", "🙅‍♂️"),
-    ("ok_woman", "🙆"),
-    ("ok_man", "🙆‍♂️"),
-    ("raising_hand_woman", "🙋"),
-    ("raising_hand_man", "🙋‍♂️"),
-    ("pouting_woman", "🙎"),
-    ("pouting_man", "🙎‍♂️"),
-    ("frowning_woman", "🙍"),
-    ("frowning_man", "🙍‍♂️"),
-    ("haircut_woman", "💇"),
-    ("haircut_man", "💇‍♂️"),
-    ("massage_woman", "💆"),
-    ("massage_man", "💆‍♂️"),
-    ("woman_in_steamy_room", "🧖‍♀️"),
-    ("man_in_steamy_room", "🧖‍♂️"),
-    ("couple_with_heart_woman_man", "💑"),
-    ("couple_with_heart_woman_woman", "👩‍❤️‍👩"),
-    ("couple_with_heart_man_man", "👨‍❤️‍👨"),
-    ("couplekiss_man_woman", "💏"),
-    ("couplekiss_woman_woman", "👩‍❤️‍💋‍👩"),
-    ("couplekiss_man_man", "👨‍❤️‍💋‍👨"),
-    ("family_man_woman_boy", "👪"),
-    ("family_man_woman_girl", "👨‍👩‍👧"),
-    ("family_man_woman_girl_boy", "👨‍👩‍👧‍👦"),
-    ("family_man_woman_boy_boy", "👨‍👩‍👦‍👦"),
-    ("family_man_woman_girl_girl", "👨‍👩‍👧‍👧"),
-    ("family_woman_woman_boy", "👩‍👩‍👦"),
-    ("family_woman_woman_girl", "👩‍👩‍👧"),
-    ("family_woman_woman_girl_boy", "👩‍👩‍👧‍👦"),
-    ("family_woman_woman_boy_boy", "👩‍👩‍👦‍👦"),
-    ("family_woman_woman_girl_girl", "👩‍👩‍👧‍👧"),
-    ("family_man_man_boy", "👨‍👨‍👦"),
-    ("family_man_man_girl", "👨‍👨‍👧"),
-    ("family_man_man_girl_boy", "👨‍👨‍👧‍👦"),
-    ("family_man_man_boy_boy", "👨‍👨‍👦‍👦"),
-    ("family_man_man_girl_girl", "👨‍👨‍👧‍👧"),
-    ("family_woman_boy", "👩‍👦"),
-    ("family_woman_girl", "👩‍👧"),
-    ("family_woman_girl_boy", "👩‍👧‍👦"),
-    ("family_woman_boy_boy", "👩‍👦‍👦"),
-    ("family_woman_girl_girl", "👩‍👧‍👧"),
-    ("family_man_boy", "👨‍👦"),
-    ("family_man_girl", "👨‍👧"),
-    ("family_man_girl_boy", "👨‍👧‍👦"),
-    ("family_man_boy_boy", "👨‍👦‍👦"),
-    ("family_man_girl_girl", "👨‍👧‍👧"),
-    ("yarn", "🧶"),
-    ("thread", "🧵"),
-    ("coat", "🧥"),
-    ("labcoat", "🥼"),
-    ("womans_clothes", "👚"),
-    ("tshirt", "👕"),
-    ("jeans", "👖"),
-    ("necktie", "👔"),
-    ("dress", "👗"),
-    ("bikini", "👙"),
-    ("kimono", "👘"),
-    ("lipstick", "💄"),
-    ("kiss", "💋"),
-    ("footprints", "👣"),
-    ("flat_shoe", "🥿"),
-    ("high_heel", "👠"),
-    ("sandal", "👡"),
-    ("boot", "👢"),
-    ("mans_shoe", "👞"),
-    ("athletic_shoe", "👟"),
-    ("hiking_boot", "🥾"),
-    ("socks", "🧦"),
-    ("gloves", "🧤"),
-    ("scarf", "🧣"),
-    ("womans_hat", "👒"),
-    ("tophat", "🎩"),
-    ("billed_hat", "🧢"),
-    ("rescue_worker_helmet", "⛑"),
-    ("mortar_board", "🎓"),
-    ("crown", "👑"),
-    ("school_satchel", "🎒"),
-    ("luggage", "🧳"),
-    ("pouch", "👝"),
-    ("purse", "👛"),
-    ("handbag", "👜"),
-    ("briefcase", "💼"),
-    ("eyeglasses", "👓"),
-    ("dark_sunglasses", "🕶"),
-    ("goggles", "🥽"),
-    ("ring", "💍"),
-    ("closed_umbrella", "🌂"),
-    ("dog", "🐶"),
-    ("cat", "🐱"),
-    ("mouse", "🐭"),
-    ("hamster", "🐹"),
-    ("rabbit", "🐰"),
-    ("fox_face", "🦊"),
-    ("bear", "🐻"),
-    ("panda_face", "🐼"),
-    ("koala", "🐨"),
-    ("tiger", "🐯"),
-    ("lion", "🦁"),
-    ("cow", "🐮"),
-    ("pig", "🐷"),
-    ("pig_nose", "🐽"),
-    ("frog", "🐸"),
-    ("squid", "🦑"),
-    ("octopus", "🐙"),
-    ("shrimp", "🦐"),
-    ("monkey_face", "🐵"),
-    ("gorilla", "🦍"),
-    ("see_no_evil", "🙈"),
-    ("hear_no_evil", "🙉"),
-    ("speak_no_evil", "🙊"),
-    ("monkey", "🐒"),
-    ("chicken", "🐔"),
-    ("penguin", "🐧"),
-    ("bird", "🐦"),
-    ("baby_chick", "🐤"),
-    ("hatching_chick", "🐣"),
-    ("hatched_chick", "🐥"),
-    ("duck", "🦆"),
-    ("eagle", "🦅"),
-    ("owl", "🦉"),
-    ("bat", "🦇"),
-    ("wolf", "🐺"),
-    ("boar", "🐗"),
-    ("horse", "🐴"),
-    ("unicorn", "🦄"),
-    ("honeybee", "🐝"),
-    ("bug", "🐛"),
-    ("butterfly", "🦋"),
-    ("snail", "🐌"),
-    ("beetle", "🐞"),
-    ("ant", "🐜"),
-    ("grasshopper", "🦗"),
-    ("spider", "🕷"),
-    ("scorpion", "🦂"),
-    ("crab", "🦀"),
-    ("snake", "🐍"),
-    ("lizard", "🦎"),
-    ("t-rex", "🦖"),
-    ("sauropod", "🦕"),
-    ("turtle", "🐢"),
-    ("tropical_fish", "🐠"),
-    ("fish", "🐟"),
-    ("blowfish", "🐡"),
-    ("dolphin", "🐬"),
-    ("shark", "🦈"),
-    ("whale", "🐳"),
-    ("whale2", "🐋"),
-    ("crocodile", "🐊"),
-    ("leopard", "🐆"),
-    ("zebra", "🦓"),
-    ("tiger2", "🐅"),
-    ("water_buffalo", "🐃"),
-    ("ox", "🐂"),
-    ("cow2", "🐄"),
-    ("deer", "🦌"),
-    ("dromedary_camel", "🐪"),
-    ("camel", "🐫"),
-    ("giraffe", "🦒"),
-    ("elephant", "🐘"),
-    ("rhinoceros", "🦏"),
-    ("goat", "🐐"),
-    ("ram", "🐏"),
-    ("sheep", "🐑"),
-    ("racehorse", "🐎"),
-    ("pig2", "🐖"),
-    ("rat", "🐀"),
-    ("mouse2", "🐁"),
-    ("rooster", "🐓"),
-    ("turkey", "🦃"),
-    ("dove", "🕊"),
-    ("dog2", "🐕"),
-    ("poodle", "🐩"),
-    ("cat2", "🐈"),
-    ("rabbit2", "🐇"),
-    ("chipmunk", "🐿"),
-    ("hedgehog", "🦔"),
-    ("raccoon", "🦝"),
-    ("llama", "🦙"),
-    ("hippopotamus", "🦛"),
-    ("kangaroo", "🦘"),
-    ("badger", "🦡"),
-    ("swan", "🦢"),
-    ("peacock", "🦚"),
-    ("parrot", "🦜"),
-    ("lobster", "🦞"),
-    ("mosquito", "🦟"),
-    ("paw_prints", "🐾"),
-    ("dragon", "🐉"),
-    ("dragon_face", "🐲"),
-    ("cactus", "🌵"),
-    ("christmas_tree", "🎄"),
-    ("evergreen_tree", "🌲"),
-    ("deciduous_tree", "🌳"),
-    ("palm_tree", "🌴"),
-    ("seedling", "🌱"),
-    ("herb", "🌿"),
-    ("shamrock", "☘"),
-    ("four_leaf_clover", "🍀"),
-    ("bamboo", "🎍"),
-    ("tanabata_tree", "🎋"),
-    ("leaves", "🍃"),
-    ("fallen_leaf", "🍂"),
-    ("maple_leaf", "🍁"),
-    ("ear_of_rice", "🌾"),
-    ("hibiscus", "🌺"),
-    ("sunflower", "🌻"),
-    ("rose", "🌹"),
-    ("wilted_flower", "🥀"),
-    ("tulip", "🌷"),
-    ("blossom", "🌼"),
-    ("cherry_blossom", "🌸"),
-    ("bouquet", "💐"),
-    ("mushroom", "🍄"),
-    ("chestnut", "🌰"),
-    ("jack_o_lantern", "🎃"),
-    ("shell", "🐚"),
-    ("spider_web", "🕸"),
-    ("earth_americas", "🌎"),
-    ("earth_africa", "🌍"),
-    ("earth_asia", "🌏"),
-    ("full_moon", "🌕"),
-    ("waning_gibbous_moon", "🌖"),
-    ("last_quarter_moon", "🌗"),
-    ("waning_crescent_moon", "🌘"),
-    ("new_moon", "🌑"),
-    ("waxing_crescent_moon", "🌒"),
-    ("first_quarter_moon", "🌓"),
-    ("waxing_gibbous_moon", "🌔"),
-    ("new_moon_with_face", "🌚"),
-    ("full_moon_with_face", "🌝"),
-    ("first_quarter_moon_with_face", "🌛"),
-    ("last_quarter_moon_with_face", "🌜"),
-    ("sun_with_face", "🌞"),
-    ("crescent_moon", "🌙"),
-    ("star", "⭐"),
-    ("star2", "🌟"),
-    ("dizzy", "💫"),
-    ("sparkles", "✨"),
-    ("comet", "☄"),
-    ("sunny", "☀️"),
-    ("sun_behind_small_cloud", "🌤"),
-    ("partly_sunny", "⛅"),
-    ("sun_behind_large_cloud", "🌥"),
-    ("sun_behind_rain_cloud", "🌦"),
-    ("cloud", "☁️"),
-    ("cloud_with_rain", "🌧"),
-    ("cloud_with_lightning_and_rain", "⛈"),
-    ("cloud_with_lightning", "🌩"),
-    ("zap", "⚡"),
-    ("fire", "🔥"),
-    ("boom", "💥"),
-    ("snowflake", "❄️"),
-    ("cloud_with_snow", "🌨"),
-    ("snowman", "⛄"),
-    ("snowman_with_snow", "☃"),
-    ("wind_face", "🌬"),
-    ("dash", "💨"),
-    ("tornado", "🌪"),
-    ("fog", "🌫"),
-    ("open_umbrella", "☂"),
-    ("umbrella", "☔"),
-    ("droplet", "💧"),
-    ("sweat_drops", "💦"),
-    ("ocean", "🌊"),
-    ("green_apple", "🍏"),
-    ("apple", "🍎"),
-    ("pear", "🍐"),
-    ("tangerine", "🍊"),
-    ("lemon", "🍋"),
-    ("banana", "🍌"),
-    ("watermelon", "🍉"),
-    ("grapes", "🍇"),
-    ("strawberry", "🍓"),
-    ("melon", "🍈"),
-    ("cherries", "🍒"),
-    ("peach", "🍑"),
-    ("pineapple", "🍍"),
-    ("coconut", "🥥"),
-    ("kiwi_fruit", "🥝"),
-    ("mango", "🥭"),
-    ("avocado", "🥑"),
-    ("broccoli", "🥦"),
-    ("tomato", "🍅"),
-    ("eggplant", "🍆"),
-    ("cucumber", "🥒"),
-    ("carrot", "🥕"),
-    ("hot_pepper", "🌶"),
-    ("potato", "🥔"),
-    ("corn", "🌽"),
-    ("leafy_greens", "🥬"),
-    ("sweet_potato", "🍠"),
-    ("peanuts", "🥜"),
-    ("honey_pot", "🍯"),
-    ("croissant", "🥐"),
-    ("bread", "🍞"),
-    ("baguette_bread", "🥖"),
-    ("bagel", "🥯"),
-    ("pretzel", "🥨"),
-    ("cheese", "🧀"),
-    ("egg", "🥚"),
-    ("bacon", "🥓"),
-    ("steak", "🥩"),
-    ("pancakes", "🥞"),
-    ("poultry_leg", "🍗"),
-    ("meat_on_bone", "🍖"),
-    ("bone", "🦴"),
-    ("fried_shrimp", "🍤"),
-    ("fried_egg", "🍳"),
-    ("hamburger", "🍔"),
-    ("fries", "🍟"),
-    ("stuffed_flatbread", "🥙"),
-    ("hotdog", "🌭"),
-    ("pizza", "🍕"),
-    ("sandwich", "🥪"),
-    ("canned_food", "🥫"),
-    ("spaghetti", "🍝"),
-    ("taco", "🌮"),
-    ("burrito", "🌯"),
-    ("green_salad", "🥗"),
-    ("shallow_pan_of_food", "🥘"),
-    ("ramen", "🍜"),
-    ("stew", "🍲"),
-    ("fish_cake", "🍥"),
-    ("fortune_cookie", "🥠"),
-    ("sushi", "🍣"),
-    ("bento", "🍱"),
-    ("curry", "🍛"),
-    ("rice_ball", "🍙"),
-    ("rice", "🍚"),
-    ("rice_cracker", "🍘"),
-    ("oden", "🍢"),
-    ("dango", "🍡"),
-    ("shaved_ice", "🍧"),
-    ("ice_cream", "🍨"),
-    ("icecream", "🍦"),
-    ("pie", "🥧"),
-    ("cake", "🍰"),
-    ("cupcake", "🧁"),
-    ("moon_cake", "🥮"),
-    ("birthday", "🎂"),
-    ("custard", "🍮"),
-    ("candy", "🍬"),
-    ("lollipop", "🍭"),
-    ("chocolate_bar", "🍫"),
-    ("popcorn", "🍿"),
-    ("dumpling", "🥟"),
-    ("doughnut", "🍩"),
-    ("cookie", "🍪"),
-    ("milk_glass", "🥛"),
-    ("beer", "🍺"),
-    ("beers", "🍻"),
-    ("clinking_glasses", "🥂"),
-    ("wine_glass", "🍷"),
-    ("tumbler_glass", "🥃"),
-    ("cocktail", "🍸"),
-    ("tropical_drink", "🍹"),
-    ("champagne", "🍾"),
-    ("sake", "🍶"),
-    ("tea", "🍵"),
-    ("cup_with_straw", "🥤"),
-    ("coffee", "☕"),
-    ("baby_bottle", "🍼"),
-    ("salt", "🧂"),
-    ("spoon", "🥄"),
-    ("fork_and_knife", "🍴"),
-    ("plate_with_cutlery", "🍽"),
-    ("bowl_with_spoon", "🥣"),
-    ("takeout_box", "🥡"),
-    ("chopsticks", "🥢"),
-    ("soccer", "⚽"),
-    ("basketball", "🏀"),
-    ("football", "🏈"),
-    ("baseball", "⚾"),
-    ("softball", "🥎"),
-    ("tennis", "🎾"),
-    ("volleyball", "🏐"),
-    ("rugby_football", "🏉"),
-    ("flying_disc", "🥏"),
-    ("8ball", "🎱"),
-    ("golf", "⛳"),
-    ("golfing_woman", "🏌️‍♀️"),
-    ("golfing_man", "🏌"),
-    ("ping_pong", "🏓"),
-    ("badminton", "🏸"),
-    ("goal_net", "🥅"),
-    ("ice_hockey", "🏒"),
-    ("field_hockey", "🏑"),
-    ("lacrosse", "🥍"),
-    ("cricket", "🏏"),
-    ("ski", "🎿"),
-    ("skier", "⛷"),
-    ("snowboarder", "🏂"),
-    ("person_fencing", "🤺"),
-    ("women_wrestling", "🤼‍♀️"),
-    ("men_wrestling", "🤼‍♂️"),
-    ("woman_cartwheeling", "🤸‍♀️"),
-    ("man_cartwheeling", "🤸‍♂️"),
-    ("woman_playing_handball", "🤾‍♀️"),
-    ("man_playing_handball", "🤾‍♂️"),
-    ("ice_skate", "⛸"),
-    ("curling_stone", "🥌"),
-    ("skateboard", "🛹"),
-    ("sled", "🛷"),
-    ("bow_and_arrow", "🏹"),
-    ("fishing_pole_and_fish", "🎣"),
-    ("boxing_glove", "🥊"),
-    ("martial_arts_uniform", "🥋"),
-    ("rowing_woman", "🚣‍♀️"),
-    ("rowing_man", "🚣"),
-    ("climbing_woman", "🧗‍♀️"),
-    ("climbing_man", "🧗‍♂️"),
-    ("swimming_woman", "🏊‍♀️"),
-    ("swimming_man", "🏊"),
-    ("woman_playing_water_polo", "🤽‍♀️"),
-    ("man_playing_water_polo", "🤽‍♂️"),
-    ("woman_in_lotus_position", "🧘‍♀️"),
-    ("man_in_lotus_position", "🧘‍♂️"),
-    ("surfing_woman", "🏄‍♀️"),
-    ("surfing_man", "🏄"),
-    ("bath", "🛀"),
-    ("basketball_woman", "⛹️‍♀️"),
-    ("basketball_man", "⛹"),
-    ("weight_lifting_woman", "🏋️‍♀️"),
-    ("weight_lifting_man", "🏋"),
-    ("biking_woman", "🚴‍♀️"),
-    ("biking_man", "🚴"),
-    ("mountain_biking_woman", "🚵‍♀️"),
-    ("mountain_biking_man", "🚵"),
-    ("horse_racing", "🏇"),
-    ("business_suit_levitating", "🕴"),
-    ("trophy", "🏆"),
-    ("running_shirt_with_sash", "🎽"),
-    ("medal_sports", "🏅"),
-    ("medal_military", "🎖"),
-    ("1st_place_medal", "🥇"),
-    ("2nd_place_medal", "🥈"),
-    ("3rd_place_medal", "🥉"),
-    ("reminder_ribbon", "🎗"),
-    ("rosette", "🏵"),
-    ("ticket", "🎫"),
-    ("tickets", "🎟"),
-    ("performing_arts", "🎭"),
-    ("art", "🎨"),
-    ("circus_tent", "🎪"),
-    ("woman_juggling", "🤹‍♀️"),
-    ("man_juggling", "🤹‍♂️"),
-    ("microphone", "🎤"),
-    ("headphones", "🎧"),
-    ("musical_score", "🎼"),
-    ("musical_keyboard", "🎹"),
-    ("drum", "🥁"),
-    ("saxophone", "🎷"),
-    ("trumpet", "🎺"),
-    ("guitar", "🎸"),
-    ("violin", "🎻"),
-    ("clapper", "🎬"),
-    ("video_game", "🎮"),
-    ("space_invader", "👾"),
-    ("dart", "🎯"),
-    ("game_die", "🎲"),
-    ("chess_pawn", "♟"),
-    ("slot_machine", "🎰"),
-    ("jigsaw", "🧩"),
-    ("bowling", "🎳"),
-    ("red_car", "🚗"),
-    ("taxi", "🚕"),
-    ("blue_car", "🚙"),
-    ("bus", "🚌"),
-    ("trolleybus", "🚎"),
-    ("racing_car", "🏎"),
-    ("police_car", "🚓"),
-    ("ambulance", "🚑"),
-    ("fire_engine", "🚒"),
-    ("minibus", "🚐"),
-    ("truck", "🚚"),
-    ("articulated_lorry", "🚛"),
-    ("tractor", "🚜"),
-    ("kick_scooter", "🛴"),
-    ("motorcycle", "🏍"),
-    ("bike", "🚲"),
-    ("motor_scooter", "🛵"),
-    ("rotating_light", "🚨"),
-    ("oncoming_police_car", "🚔"),
-    ("oncoming_bus", "🚍"),
-    ("oncoming_automobile", "🚘"),
-    ("oncoming_taxi", "🚖"),
-    ("aerial_tramway", "🚡"),
-    ("mountain_cableway", "🚠"),
-    ("suspension_railway", "🚟"),
-    ("railway_car", "🚃"),
-    ("train", "🚋"),
-    ("monorail", "🚝"),
-    ("bullettrain_side", "🚄"),
-    ("bullettrain_front", "🚅"),
-    ("light_rail", "🚈"),
-    ("mountain_railway", "🚞"),
-    ("steam_locomotive", "🚂"),
-    ("train2", "🚆"),
-    ("metro", "🚇"),
-    ("tram", "🚊"),
-    ("station", "🚉"),
-    ("flying_saucer", "🛸"),
-    ("helicopter", "🚁"),
-    ("small_airplane", "🛩"),
-    ("airplane", "✈️"),
-    ("flight_departure", "🛫"),
-    ("flight_arrival", "🛬"),
-    ("sailboat", "⛵"),
-    ("motor_boat", "🛥"),
-    ("speedboat", "🚤"),
-    ("ferry", "⛴"),
-    ("passenger_ship", "🛳"),
-    ("rocket", "🚀"),
-    ("artificial_satellite", "🛰"),
-    ("seat", "💺"),
-    ("canoe", "🛶"),
-    ("anchor", "⚓"),
-    ("construction", "🚧"),
-    ("fuelpump", "⛽"),
-    ("busstop", "🚏"),
-    ("vertical_traffic_light", "🚦"),
-    ("traffic_light", "🚥"),
-    ("checkered_flag", "🏁"),
-    ("ship", "🚢"),
-    ("ferris_wheel", "🎡"),
-    ("roller_coaster", "🎢"),
-    ("carousel_horse", "🎠"),
-    ("building_construction", "🏗"),
-    ("foggy", "🌁"),
-    ("tokyo_tower", "🗼"),
-    ("factory", "🏭"),
-    ("fountain", "⛲"),
-    ("rice_scene", "🎑"),
-    ("mountain", "⛰"),
-    ("mountain_snow", "🏔"),
-    ("mount_fuji", "🗻"),
-    ("volcano", "🌋"),
-    ("japan", "🗾"),
-    ("camping", "🏕"),
-    ("tent", "⛺"),
-    ("national_park", "🏞"),
-    ("motorway", "🛣"),
-    ("railway_track", "🛤"),
-    ("sunrise", "🌅"),
-    ("sunrise_over_mountains", "🌄"),
-    ("desert", "🏜"),
-    ("beach_umbrella", "🏖"),
-    ("desert_island", "🏝"),
-    ("city_sunrise", "🌇"),
-    ("city_sunset", "🌆"),
-    ("cityscape", "🏙"),
-    ("night_with_stars", "🌃"),
-    ("bridge_at_night", "🌉"),
-    ("milky_way", "🌌"),
-    ("stars", "🌠"),
-    ("sparkler", "🎇"),
-    ("fireworks", "🎆"),
-    ("rainbow", "🌈"),
-    ("houses", "🏘"),
-    ("european_castle", "🏰"),
-    ("japanese_castle", "🏯"),
-    ("stadium", "🏟"),
-    ("statue_of_liberty", "🗽"),
-    ("house", "🏠"),
-    ("house_with_garden", "🏡"),
-    ("derelict_house", "🏚"),
-    ("office", "🏢"),
-    ("department_store", "🏬"),
-    ("post_office", "🏣"),
-    ("european_post_office", "🏤"),
-    ("hospital", "🏥"),
-    ("bank", "🏦"),
-    ("hotel", "🏨"),
-    ("convenience_store", "🏪"),
-    ("school", "🏫"),
-    ("love_hotel", "🏩"),
-    ("wedding", "💒"),
-    ("classical_building", "🏛"),
-    ("church", "⛪"),
-    ("mosque", "🕌"),
-    ("synagogue", "🕍"),
-    ("kaaba", "🕋"),
-    ("shinto_shrine", "⛩"),
-    ("watch", "⌚"),
-    ("iphone", "📱"),
-    ("calling", "📲"),
-    ("computer", "💻"),
-    ("keyboard", "⌨"),
-    ("desktop_computer", "🖥"),
-    ("printer", "🖨"),
-    ("computer_mouse", "🖱"),
-    ("trackball", "🖲"),
-    ("joystick", "🕹"),
-    ("clamp", "🗜"),
-    ("minidisc", "💽"),
-    ("floppy_disk", "💾"),
-    ("cd", "💿"),
-    ("dvd", "📀"),
-    ("vhs", "📼"),
-    ("camera", "📷"),
-    ("camera_flash", "📸"),
-    ("video_camera", "📹"),
-    ("movie_camera", "🎥"),
-    ("film_projector", "📽"),
-    ("film_strip", "🎞"),
-    ("telephone_receiver", "📞"),
-    ("phone", "☎️"),
-    ("pager", "📟"),
-    ("fax", "📠"),
-    ("tv", "📺"),
-    ("radio", "📻"),
-    ("studio_microphone", "🎙"),
-    ("level_slider", "🎚"),
-    ("control_knobs", "🎛"),
-    ("compass", "🧭"),
-    ("stopwatch", "⏱"),
-    ("timer_clock", "⏲"),
-    ("alarm_clock", "⏰"),
-    ("mantelpiece_clock", "🕰"),
-    ("hourglass_flowing_sand", "⏳"),
-    ("hourglass", "⌛"),
-    ("satellite", "📡"),
-    ("battery", "🔋"),
-    ("electric_plug", "🔌"),
-    ("bulb", "💡"),
-    ("flashlight", "🔦"),
-    ("candle", "🕯"),
-    ("fire_extinguisher", "🧯"),
-    ("wastebasket", "🗑"),
-    ("oil_drum", "🛢"),
-    ("money_with_wings", "💸"),
-    ("dollar", "💵"),
-    ("yen", "💴"),
-    ("euro", "💶"),
-    ("pound", "💷"),
-    ("moneybag", "💰"),
-    ("credit_card", "💳"),
-    ("gem", "💎"),
-    ("balance_scale", "⚖"),
-    ("toolbox", "🧰"),
-    ("wrench", "🔧"),
-    ("hammer", "🔨"),
-    ("hammer_and_pick", "⚒"),
-    ("hammer_and_wrench", "🛠"),
-    ("pick", "⛏"),
-    ("nut_and_bolt", "🔩"),
-    ("gear", "⚙"),
-    ("brick", "🧱"),
-    ("chains", "⛓"),
-    ("magnet", "🧲"),
-    ("gun", "🔫"),
-    ("bomb", "💣"),
-    ("firecracker", "🧨"),
-    ("hocho", "🔪"),
-    ("dagger", "🗡"),
-    ("crossed_swords", "⚔"),
-    ("shield", "🛡"),
-    ("smoking", "🚬"),
-    ("skull_and_crossbones", "☠"),
-    ("coffin", "⚰"),
-    ("funeral_urn", "⚱"),
-    ("amphora", "🏺"),
-    ("crystal_ball", "🔮"),
-    ("prayer_beads", "📿"),
-    ("nazar_amulet", "🧿"),
-    ("barber", "💈"),
-    ("alembic", "⚗"),
-    ("telescope", "🔭"),
-    ("microscope", "🔬"),
-    ("hole", "🕳"),
-    ("pill", "💊"),
-    ("syringe", "💉"),
-    ("dna", "🧬"),
-    ("microbe", "🦠"),
-    ("petri_dish", "🧫"),
-    ("test_tube", "🧪"),
-    ("thermometer", "🌡"),
-    ("broom", "🧹"),
-    ("basket", "🧺"),
-    ("toilet_paper", "🧻"),
-    ("label", "🏷"),
-    ("bookmark", "🔖"),
-    ("toilet", "🚽"),
-    ("shower", "🚿"),
-    ("bathtub", "🛁"),
-    ("soap", "🧼"),
-    ("sponge", "🧽"),
-    ("lotion_bottle", "🧴"),
-    ("key", "🔑"),
-    ("old_key", "🗝"),
-    ("couch_and_lamp", "🛋"),
-    ("sleeping_bed", "🛌"),
-    ("bed", "🛏"),
-    ("door", "🚪"),
-    ("bellhop_bell", "🛎"),
-    ("teddy_bear", "🧸"),
-    ("framed_picture", "🖼"),
-    ("world_map", "🗺"),
-    ("parasol_on_ground", "⛱"),
-    ("moyai", "🗿"),
-    ("shopping", "🛍"),
-    ("shopping_cart", "🛒"),
-    ("balloon", "🎈"),
-    ("flags", "🎏"),
-    ("ribbon", "🎀"),
-    ("gift", "🎁"),
-    ("confetti_ball", "🎊"),
-    ("tada", "🎉"),
-    ("dolls", "🎎"),
-    ("wind_chime", "🎐"),
-    ("crossed_flags", "🎌"),
-    ("izakaya_lantern", "🏮"),
-    ("red_envelope", "🧧"),
-    ("email", "✉️"),
-    ("envelope_with_arrow", "📩"),
-    ("incoming_envelope", "📨"),
-    ("e-mail", "📧"),
-    ("love_letter", "💌"),
-    ("postbox", "📮"),
-    ("mailbox_closed", "📪"),
-    ("mailbox", "📫"),
-    ("mailbox_with_mail", "📬"),
-    ("mailbox_with_no_mail", "📭"),
-    ("package", "📦"),
-    ("postal_horn", "📯"),
-    ("inbox_tray", "📥"),
-    ("outbox_tray", "📤"),
-    ("scroll", "📜"),
-    ("page_with_curl", "📃"),
-    ("bookmark_tabs", "📑"),
-    ("receipt", "🧾"),
-    ("bar_chart", "📊"),
-    ("chart_with_upwards_trend", "📈"),
-    ("chart_with_downwards_trend", "📉"),
-    ("page_facing_up", "📄"),
-    ("date", "📅"),
-    ("calendar", "📆"),
-    ("spiral_calendar", "🗓"),
-    ("card_index", "📇"),
-    ("card_file_box", "🗃"),
-    ("ballot_box", "🗳"),
-    ("file_cabinet", "🗄"),
-    ("clipboard", "📋"),
-    ("spiral_notepad", "🗒"),
-    ("file_folder", "📁"),
-    ("open_file_folder", "📂"),
-    ("card_index_dividers", "🗂"),
-    ("newspaper_roll", "🗞"),
-    ("newspaper", "📰"),
-    ("notebook", "📓"),
-    ("closed_book", "📕"),
-    ("green_book", "📗"),
-    ("blue_book", "📘"),
-    ("orange_book", "📙"),
-    ("notebook_with_decorative_cover", "📔"),
-    ("ledger", "📒"),
-    ("books", "📚"),
-    ("open_book", "📖"),
-    ("safety_pin", "🧷"),
-    ("link", "🔗"),
-    ("paperclip", "📎"),
-    ("paperclips", "🖇"),
-    ("scissors", "✂️"),
-    ("triangular_ruler", "📐"),
-    ("straight_ruler", "📏"),
-    ("abacus", "🧮"),
-    ("pushpin", "📌"),
-    ("round_pushpin", "📍"),
-    ("triangular_flag_on_post", "🚩"),
-    ("white_flag", "🏳"),
-    ("black_flag", "🏴"),
-    ("rainbow_flag", "🏳️‍🌈"),
-    ("closed_lock_with_key", "🔐"),
-    ("lock", "🔒"),
-    ("unlock", "🔓"),
-    ("lock_with_ink_pen", "🔏"),
-    ("pen", "🖊"),
-    ("fountain_pen", "🖋"),
-    ("black_nib", "✒️"),
-    ("memo", "📝"),
-    ("pencil2", "✏️"),
-    ("crayon", "🖍"),
-    ("paintbrush", "🖌"),
-    ("mag", "🔍"),
-    ("mag_right", "🔎"),
-    ("heart", "❤️"),
-    ("orange_heart", "🧡"),
-    ("yellow_heart", "💛"),
-    ("green_heart", "💚"),
-    ("blue_heart", "💙"),
-    ("purple_heart", "💜"),
-    ("black_heart", "🖤"),
-    ("broken_heart", "💔"),
-    ("heavy_heart_exclamation", "❣"),
-    ("two_hearts", "💕"),
-    ("revolving_hearts", "💞"),
-    ("heartbeat", "💓"),
-    ("heartpulse", "💗"),
-    ("sparkling_heart", "💖"),
-    ("cupid", "💘"),
-    ("gift_heart", "💝"),
-    ("heart_decoration", "💟"),
-    ("peace_symbol", "☮"),
-    ("latin_cross", "✝"),
-    ("star_and_crescent", "☪"),
-    ("om", "🕉"),
-    ("wheel_of_dharma", "☸"),
-    ("star_of_david", "✡"),
-    ("six_pointed_star", "🔯"),
-    ("menorah", "🕎"),
-    ("yin_yang", "☯"),
-    ("orthodox_cross", "☦"),
-    ("place_of_worship", "🛐"),
-    ("ophiuchus", "⛎"),
-    ("aries", "♈"),
-    ("taurus", "♉"),
-    ("gemini", "♊"),
-    ("cancer", "♋"),
-    ("leo", "♌"),
-    ("virgo", "♍"),
-    ("libra", "♎"),
-    ("scorpius", "♏"),
-    ("sagittarius", "♐"),
-    ("capricorn", "♑"),
-    ("aquarius", "♒"),
-    ("pisces", "♓"),
-    ("id", "🆔"),
-    ("atom_symbol", "⚛"),
-    ("u7a7a", "🈳"),
-    ("u5272", "🈹"),
-    ("radioactive", "☢"),
-    ("biohazard", "☣"),
-    ("mobile_phone_off", "📴"),
-    ("vibration_mode", "📳"),
-    ("u6709", "🈶"),
-    ("u7121", "🈚"),
-    ("u7533", "🈸"),
-    ("u55b6", "🈺"),
-    ("u6708", "🈷️"),
-    ("eight_pointed_black_star", "✴️"),
-    ("vs", "🆚"),
-    ("accept", "🉑"),
-    ("white_flower", "💮"),
-    ("ideograph_advantage", "🉐"),
-    ("secret", "㊙️"),
-    ("congratulations", "㊗️"),
-    ("u5408", "🈴"),
-    ("u6e80", "🈵"),
-    ("u7981", "🈲"),
-    ("a", "🅰️"),
-    ("b", "🅱️"),
-    ("ab", "🆎"),
-    ("cl", "🆑"),
-    ("o2", "🅾️"),
-    ("sos", "🆘"),
-    ("no_entry", "⛔"),
-    ("name_badge", "📛"),
-    ("no_entry_sign", "🚫"),
-    ("x", "❌"),
-    ("o", "⭕"),
-    ("stop_sign", "🛑"),
-    ("anger", "💢"),
-    ("hotsprings", "♨️"),
-    ("no_pedestrians", "🚷"),
-    ("do_not_litter", "🚯"),
-    ("no_bicycles", "🚳"),
-    ("non-potable_water", "🚱"),
-    ("underage", "🔞"),
-    ("no_mobile_phones", "📵"),
-    ("exclamation", "❗"),
-    ("grey_exclamation", "❕"),
-    ("question", "❓"),
-    ("grey_question", "❔"),
-    ("bangbang", "‼️"),
-    ("interrobang", "⁉️"),
-    ("100", "💯"),
-    ("low_brightness", "🔅"),
-    ("high_brightness", "🔆"),
-    ("trident", "🔱"),
-    ("fleur_de_lis", "⚜"),
-    ("part_alternation_mark", "〽️"),
-    ("warning", "⚠️"),
-    ("children_crossing", "🚸"),
-    ("beginner", "🔰"),
-    ("recycle", "♻️"),
-    ("u6307", "🈯"),
-    ("chart", "💹"),
-    ("sparkle", "❇️"),
-    ("eight_spoked_asterisk", "✳️"),
-    ("negative_squared_cross_mark", "❎"),
-    ("white_check_mark", "✅"),
-    ("diamond_shape_with_a_dot_inside", "💠"),
-    ("cyclone", "🌀"),
-    ("loop", "➿"),
-    ("globe_with_meridians", "🌐"),
-    ("m", "Ⓜ️"),
-    ("atm", "🏧"),
-    ("sa", "🈂️"),
-    ("passport_control", "🛂"),
-    ("customs", "🛃"),
-    ("baggage_claim", "🛄"),
-    ("left_luggage", "🛅"),
-    ("wheelchair", "♿"),
-    ("no_smoking", "🚭"),
-    ("wc", "🚾"),
-    ("parking", "🅿️"),
-    ("potable_water", "🚰"),
-    ("mens", "🚹"),
-    ("womens", "🚺"),
-    ("baby_symbol", "🚼"),
-    ("restroom", "🚻"),
-    ("put_litter_in_its_place", "🚮"),
-    ("cinema", "🎦"),
-    ("signal_strength", "📶"),
-    ("koko", "🈁"),
-    ("ng", "🆖"),
-    ("ok", "🆗"),
-    ("up", "🆙"),
-    ("cool", "🆒"),
-    ("new", "🆕"),
-    ("free", "🆓"),
-    ("zero", "0️⃣"),
-    ("one", "1️⃣"),
-    ("two", "2️⃣"),
-    ("three", "3️⃣"),
-    ("four", "4️⃣"),
-    ("five", "5️⃣"),
-    ("six", "6️⃣"),
-    ("seven", "7️⃣"),
-    ("eight", "8️⃣"),
-    ("nine", "9️⃣"),
-    ("keycap_ten", "🔟"),
-    ("asterisk", "*⃣"),
-    ("1234", "🔢"),
-    ("eject_button", "⏏️"),
-    ("arrow_forward", "▶️"),
-    ("pause_button", "⏸"),
-    ("next_track_button", "⏭"),
-    ("stop_button", "⏹"),
-    ("record_button", "⏺"),
-    ("play_or_pause_button", "⏯"),
-    ("previous_track_button", "⏮"),
-    ("fast_forward", "⏩"),
-    ("rewind", "⏪"),
-    ("twisted_rightwards_arrows", "🔀"),
-    ("repeat", "🔁"),
-    ("repeat_one", "🔂"),
-    ("arrow_backward", "◀️"),
-    ("arrow_up_small", "🔼"),
-    ("arrow_down_small", "🔽"),
-    ("arrow_double_up", "⏫"),
-    ("arrow_double_down", "⏬"),
-    ("arrow_right", "➡️"),
-    ("arrow_left", "⬅️"),
-    ("arrow_up", "⬆️"),
-    ("arrow_down", "⬇️"),
-    ("arrow_upper_right", "↗️"),
-    ("arrow_lower_right", "↘️"),
-    ("arrow_lower_left", "↙️"),
-    ("arrow_upper_left", "↖️"),
-    ("arrow_up_down", "↕️"),
-    ("left_right_arrow", "↔️"),
-    ("arrows_counterclockwise", "🔄"),
-    ("arrow_right_hook", "↪️"),
-    ("leftwards_arrow_with_hook", "↩️"),
-    ("arrow_heading_up", "⤴️"),
-    ("arrow_heading_down", "⤵️"),
-    ("hash", "#️⃣"),
-    ("information_source", "ℹ️"),
-    ("abc", "🔤"),
-    ("abcd", "🔡"),
-    ("capital_abcd", "🔠"),
-    ("symbols", "🔣"),
-    ("musical_note", "🎵"),
-    ("notes", "🎶"),
-    ("wavy_dash", "〰️"),
-    ("curly_loop", "➰"),
-    ("heavy_check_mark", "✔️"),
-    ("arrows_clockwise", "🔃"),
-    ("heavy_plus_sign", "➕"),
-    ("heavy_minus_sign", "➖"),
-    ("heavy_division_sign", "➗"),
-    ("heavy_multiplication_x", "✖️"),
-    ("infinity", "♾"),
-    ("heavy_dollar_sign", "💲"),
-    ("currency_exchange", "💱"),
-    ("copyright", "©️"),
-    ("registered", "®️"),
-    ("tm", "™️"),
-    ("end", "🔚"),
-    ("back", "🔙"),
-    ("on", "🔛"),
-    ("top", "🔝"),
-    ("soon", "🔜"),
-    ("ballot_box_with_check", "☑️"),
-    ("radio_button", "🔘"),
-    ("white_circle", "⚪"),
-    ("black_circle", "⚫"),
-    ("red_circle", "🔴"),
-    ("large_blue_circle", "🔵"),
-    ("small_orange_diamond", "🔸"),
-    ("small_blue_diamond", "🔹"),
-    ("large_orange_diamond", "🔶"),
-    ("large_blue_diamond", "🔷"),
-    ("small_red_triangle", "🔺"),
-    ("black_small_square", "▪️"),
-    ("white_small_square", "▫️"),
-    ("black_large_square", "⬛"),
-    ("white_large_square", "⬜"),
-    ("small_red_triangle_down", "🔻"),
-    ("black_medium_square", "◼️"),
-    ("white_medium_square", "◻️"),
-    ("black_medium_small_square", "◾"),
-    ("white_medium_small_square", "◽"),
-    ("black_square_button", "🔲"),
-    ("white_square_button", "🔳"),
-    ("speaker", "🔈"),
-    ("sound", "🔉"),
-    ("loud_sound", "🔊"),
-    ("mute", "🔇"),
-    ("mega", "📣"),
-    ("loudspeaker", "📢"),
-    ("bell", "🔔"),
-    ("no_bell", "🔕"),
-    ("black_joker", "🃏"),
-    ("mahjong", "🀄"),
-    ("spades", "♠️"),
-    ("clubs", "♣️"),
-    ("hearts", "♥️"),
-    ("diamonds", "♦️"),
-    ("flower_playing_cards", "🎴"),
-    ("thought_balloon", "💭"),
-    ("right_anger_bubble", "🗯"),
-    ("speech_balloon", "💬"),
-    ("left_speech_bubble", "🗨"),
-    ("clock1", "🕐"),
-    ("clock2", "🕑"),
-    ("clock3", "🕒"),
-    ("clock4", "🕓"),
-    ("clock5", "🕔"),
-    ("clock6", "🕕"),
-    ("clock7", "🕖"),
-    ("clock8", "🕗"),
-    ("clock9", "🕘"),
-    ("clock10", "🕙"),
-    ("clock11", "🕚"),
-    ("clock12", "🕛"),
-    ("clock130", "🕜"),
-    ("clock230", "🕝"),
-    ("clock330", "🕞"),
-    ("clock430", "🕟"),
-    ("clock530", "🕠"),
-    ("clock630", "🕡"),
-    ("clock730", "🕢"),
-    ("clock830", "🕣"),
-    ("clock930", "🕤"),
-    ("clock1030", "🕥"),
-    ("clock1130", "🕦"),
-    ("clock1230", "🕧"),
-    ("afghanistan", "🇦🇫"),
-    ("aland_islands", "🇦🇽"),
-    ("albania", "🇦🇱"),
-    ("algeria", "🇩🇿"),
-    ("american_samoa", "🇦🇸"),
-    ("andorra", "🇦🇩"),
-    ("angola", "🇦🇴"),
-    ("anguilla", "🇦🇮"),
-    ("antarctica", "🇦🇶"),
-    ("antigua_barbuda", "🇦🇬"),
-    ("argentina", "🇦🇷"),
-    ("armenia", "🇦🇲"),
-    ("aruba", "🇦🇼"),
-    ("australia", "🇦🇺"),
-    ("austria", "🇦🇹"),
-    ("azerbaijan", "🇦🇿"),
-    ("bahamas", "🇧🇸"),
-    ("bahrain", "🇧🇭"),
-    ("bangladesh", "🇧🇩"),
-    ("barbados", "🇧🇧"),
-    ("belarus", "🇧🇾"),
-    ("belgium", "🇧🇪"),
-    ("belize", "🇧🇿"),
-    ("benin", "🇧🇯"),
-    ("bermuda", "🇧🇲"),
-    ("bhutan", "🇧🇹"),
-    ("bolivia", "🇧🇴"),
-    ("caribbean_netherlands", "🇧🇶"),
-    ("bosnia_herzegovina", "🇧🇦"),
-    ("botswana", "🇧🇼"),
-    ("brazil", "🇧🇷"),
-    ("british_indian_ocean_territory", "🇮🇴"),
-    ("british_virgin_islands", "🇻🇬"),
-    ("brunei", "🇧🇳"),
-    ("bulgaria", "🇧🇬"),
-    ("burkina_faso", "🇧🇫"),
-    ("burundi", "🇧🇮"),
-    ("cape_verde", "🇨🇻"),
-    ("cambodia", "🇰🇭"),
-    ("cameroon", "🇨🇲"),
-    ("canada", "🇨🇦"),
-    ("canary_islands", "🇮🇨"),
-    ("cayman_islands", "🇰🇾"),
-    ("central_african_republic", "🇨🇫"),
-    ("chad", "🇹🇩"),
-    ("chile", "🇨🇱"),
-    ("cn", "🇨🇳"),
-    ("christmas_island", "🇨🇽"),
-    ("cocos_islands", "🇨🇨"),
-    ("colombia", "🇨🇴"),
-    ("comoros", "🇰🇲"),
-    ("congo_brazzaville", "🇨🇬"),
-    ("congo_kinshasa", "🇨🇩"),
-    ("cook_islands", "🇨🇰"),
-    ("costa_rica", "🇨🇷"),
-    ("croatia", "🇭🇷"),
-    ("cuba", "🇨🇺"),
-    ("curacao", "🇨🇼"),
-    ("cyprus", "🇨🇾"),
-    ("czech_republic", "🇨🇿"),
-    ("denmark", "🇩🇰"),
-    ("djibouti", "🇩🇯"),
-    ("dominica", "🇩🇲"),
-    ("dominican_republic", "🇩🇴"),
-    ("ecuador", "🇪🇨"),
-    ("egypt", "🇪🇬"),
-    ("el_salvador", "🇸🇻"),
-    ("equatorial_guinea", "🇬🇶"),
-    ("eritrea", "🇪🇷"),
-    ("estonia", "🇪🇪"),
-    ("ethiopia", "🇪🇹"),
-    ("eu", "🇪🇺"),
-    ("falkland_islands", "🇫🇰"),
-    ("faroe_islands", "🇫🇴"),
-    ("fiji", "🇫🇯"),
-    ("finland", "🇫🇮"),
-    ("fr", "🇫🇷"),
-    ("french_guiana", "🇬🇫"),
-    ("french_polynesia", "🇵🇫"),
-    ("french_southern_territories", "🇹🇫"),
-    ("gabon", "🇬🇦"),
-    ("gambia", "🇬🇲"),
-    ("georgia", "🇬🇪"),
-    ("de", "🇩🇪"),
-    ("ghana", "🇬🇭"),
-    ("gibraltar", "🇬🇮"),
-    ("greece", "🇬🇷"),
-    ("greenland", "🇬🇱"),
-    ("grenada", "🇬🇩"),
-    ("guadeloupe", "🇬🇵"),
-    ("guam", "🇬🇺"),
-    ("guatemala", "🇬🇹"),
-    ("guernsey", "🇬🇬"),
-    ("guinea", "🇬🇳"),
-    ("guinea_bissau", "🇬🇼"),
-    ("guyana", "🇬🇾"),
-    ("haiti", "🇭🇹"),
-    ("honduras", "🇭🇳"),
-    ("hong_kong", "🇭🇰"),
-    ("hungary", "🇭🇺"),
-    ("iceland", "🇮🇸"),
-    ("india", "🇮🇳"),
-    ("indonesia", "🇮🇩"),
-    ("iran", "🇮🇷"),
-    ("iraq", "🇮🇶"),
-    ("ireland", "🇮🇪"),
-    ("isle_of_man", "🇮🇲"),
-    ("israel", "🇮🇱"),
-    ("it", "🇮🇹"),
-    ("cote_divoire", "🇨🇮"),
-    ("jamaica", "🇯🇲"),
-    ("jp", "🇯🇵"),
-    ("jersey", "🇯🇪"),
-    ("jordan", "🇯🇴"),
-    ("kazakhstan", "🇰🇿"),
-    ("kenya", "🇰🇪"),
-    ("kiribati", "🇰🇮"),
-    ("kosovo", "🇽🇰"),
-    ("kuwait", "🇰🇼"),
-    ("kyrgyzstan", "🇰🇬"),
-    ("laos", "🇱🇦"),
-    ("latvia", "🇱🇻"),
-    ("lebanon", "🇱🇧"),
-    ("lesotho", "🇱🇸"),
-    ("liberia", "🇱🇷"),
-    ("libya", "🇱🇾"),
-    ("liechtenstein", "🇱🇮"),
-    ("lithuania", "🇱🇹"),
-    ("luxembourg", "🇱🇺"),
-    ("macau", "🇲🇴"),
-    ("macedonia", "🇲🇰"),
-    ("madagascar", "🇲🇬"),
-    ("malawi", "🇲🇼"),
-    ("malaysia", "🇲🇾"),
-    ("maldives", "🇲🇻"),
-    ("mali", "🇲🇱"),
-    ("malta", "🇲🇹"),
-    ("marshall_islands", "🇲🇭"),
-    ("martinique", "🇲🇶"),
-    ("mauritania", "🇲🇷"),
-    ("mauritius", "🇲🇺"),
-    ("mayotte", "🇾🇹"),
-    ("mexico", "🇲🇽"),
-    ("micronesia", "🇫🇲"),
-    ("moldova", "🇲🇩"),
-    ("monaco", "🇲🇨"),
-    ("mongolia", "🇲🇳"),
-    ("montenegro", "🇲🇪"),
-    ("montserrat", "🇲🇸"),
-    ("morocco", "🇲🇦"),
-    ("mozambique", "🇲🇿"),
-    ("myanmar", "🇲🇲"),
-    ("namibia", "🇳🇦"),
-    ("nauru", "🇳🇷"),
-    ("nepal", "🇳🇵"),
-    ("netherlands", "🇳🇱"),
-    ("new_caledonia", "🇳🇨"),
-    ("new_zealand", "🇳🇿"),
-    ("nicaragua", "🇳🇮"),
-    ("niger", "🇳🇪"),
-    ("nigeria", "🇳🇬"),
-    ("niue", "🇳🇺"),
-    ("norfolk_island", "🇳🇫"),
-    ("northern_mariana_islands", "🇲🇵"),
-    ("north_korea", "🇰🇵"),
-    ("norway", "🇳🇴"),
-    ("oman", "🇴🇲"),
-    ("pakistan", "🇵🇰"),
-    ("palau", "🇵🇼"),
-    ("palestinian_territories", "🇵🇸"),
-    ("panama", "🇵🇦"),
-    ("papua_new_guinea", "🇵🇬"),
-    ("paraguay", "🇵🇾"),
-    ("peru", "🇵🇪"),
-    ("philippines", "🇵🇭"),
-    ("pitcairn_islands", "🇵🇳"),
-    ("poland", "🇵🇱"),
-    ("portugal", "🇵🇹"),
-    ("puerto_rico", "🇵🇷"),
-    ("qatar", "🇶🇦"),
-    ("reunion", "🇷🇪"),
-    ("romania", "🇷🇴"),
-    ("ru", "🇷🇺"),
-    ("rwanda", "🇷🇼"),
-    ("st_barthelemy", "🇧🇱"),
-    ("st_helena", "🇸🇭"),
-    ("st_kitts_nevis", "🇰🇳"),
-    ("st_lucia", "🇱🇨"),
-    ("st_pierre_miquelon", "🇵🇲"),
-    ("st_vincent_grenadines", "🇻🇨"),
-    ("samoa", "🇼🇸"),
-    ("san_marino", "🇸🇲"),
-    ("sao_tome_principe", "🇸🇹"),
-    ("saudi_arabia", "🇸🇦"),
-    ("senegal", "🇸🇳"),
-    ("serbia", "🇷🇸"),
-    ("seychelles", "🇸🇨"),
-    ("sierra_leone", "🇸🇱"),
-    ("singapore", "🇸🇬"),
-    ("sint_maarten", "🇸🇽"),
-    ("slovakia", "🇸🇰"),
-    ("slovenia", "🇸🇮"),
-    ("solomon_islands", "🇸🇧"),
-    ("somalia", "🇸🇴"),
-    ("south_africa", "🇿🇦"),
-    ("south_georgia_south_sandwich_islands", "🇬🇸"),
-    ("kr", "🇰🇷"),
-    ("south_sudan", "🇸🇸"),
-    ("es", "🇪🇸"),
-    ("sri_lanka", "🇱🇰"),
-    ("sudan", "🇸🇩"),
-    ("suriname", "🇸🇷"),
-    ("swaziland", "🇸🇿"),
-    ("sweden", "🇸🇪"),
-    ("switzerland", "🇨🇭"),
-    ("syria", "🇸🇾"),
-    ("taiwan", "🇹🇼"),
-    ("tajikistan", "🇹🇯"),
-    ("tanzania", "🇹🇿"),
-    ("thailand", "🇹🇭"),
-    ("timor_leste", "🇹🇱"),
-    ("togo", "🇹🇬"),
-    ("tokelau", "🇹🇰"),
-    ("tonga", "🇹🇴"),
-    ("trinidad_tobago", "🇹🇹"),
-    ("tunisia", "🇹🇳"),
-    ("tr", "🇹🇷"),
-    ("turkmenistan", "🇹🇲"),
-    ("turks_caicos_islands", "🇹🇨"),
-    ("tuvalu", "🇹🇻"),
-    ("uganda", "🇺🇬"),
-    ("ukraine", "🇺🇦"),
-    ("united_arab_emirates", "🇦🇪"),
-    ("uk", "🇬🇧"),
-    ("england", "🏴󠁧󠁢󠁥󠁮󠁧󠁿"),
-    ("scotland", "🏴󠁧󠁢󠁳󠁣󠁴󠁿"),
-    ("wales", "🏴󠁧󠁢󠁷󠁬󠁳󠁿"),
-    ("us", "🇺🇸"),
-    ("us_virgin_islands", "🇻🇮"),
-    ("uruguay", "🇺🇾"),
-    ("uzbekistan", "🇺🇿"),
-    ("vanuatu", "🇻🇺"),
-    ("vatican_city", "🇻🇦"),
-    ("venezuela", "🇻🇪"),
-    ("vietnam", "🇻🇳"),
-    ("wallis_futuna", "🇼🇫"),
-    ("western_sahara", "🇪🇭"),
-    ("yemen", "🇾🇪"),
-    ("zambia", "🇿🇲"),
-    ("zimbabwe", "🇿🇼"),
-    ("united_nations", "🇺🇳"),
-    ("pirate_flag", "🏴‍☠️"),
+use std::borrow::Cow;
+
+#[cfg(feature = "druid")]
+use druid::Data;
+use serde::{Deserialize, Serialize};
+
+use self::Category::*;
+
+/// The groups the emojis are split into, in the order they appear in `EMOJIS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "druid", derive(Data))]
+pub enum Category {
+    Smileys,
+    Animals,
+    Food,
+    Activity,
+    Travel,
+    Objects,
+    Symbols,
+    Flags,
+}
+
+impl Category {
+    pub const ALL: &'static [Category] =
+        &[Smileys, Animals, Food, Activity, Travel, Objects, Symbols, Flags];
+
+    /// The emoji shown on the category's tab.
+    pub fn icon(self) -> &'static str {
+        match self {
+            Smileys => "😀",
+            Animals => "🐶",
+            Food => "🍏",
+            Activity => "⚽",
+            Travel => "🚗",
+            Objects => "⌚",
+            Symbols => "❤️",
+            Flags => "🏁",
+        }
+    }
+
+    /// The name of the CLDR group the category stands for.
+    pub fn name(self) -> &'static str {
+        match self {
+            Smileys => "Smileys & People",
+            Animals => "Animals & Nature",
+            Food => "Food & Drink",
+            Activity => "Activities",
+            Travel => "Travel & Places",
+            Objects => "Objects",
+            Symbols => "Symbols",
+            Flags => "Flags",
+        }
+    }
+
+    /// The category `search` names the CLDR group of, like "Animals & Nature", without
+    /// regard to case. The newer "Smileys & Emotion" and "People & Body" are both
+    /// `Smileys`. As with subgroups, single words like "Objects" are searched as text.
+    pub fn named(search: &str) -> Option<Category> {
+        if !search.contains(' ') {
+            return None;
+        }
+        let is = |name: &str| name.eq_ignore_ascii_case(search);
+        if is("Smileys & Emotion") || is("People & Body") {
+            return Some(Smileys);
+        }
+        Self::ALL.iter().copied().find(|cat| is(cat.name()))
+    }
+
+    /// A word every emoji in the category is also found by.
+    pub(crate) fn keyword(self) -> Option<&'static str> {
+        match self {
+            Flags => Some("flag"),
+            _ => None,
+        }
+    }
+}
+
+/// A Fitzpatrick skin tone modifier, `Default` leaves emojis their standard yellow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "druid", derive(Data))]
+#[serde(rename_all = "snake_case")]
+pub enum SkinTone {
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    pub const ALL: &'static [SkinTone] = &[
+        SkinTone::Default,
+        SkinTone::Light,
+        SkinTone::MediumLight,
+        SkinTone::Medium,
+        SkinTone::MediumDark,
+        SkinTone::Dark,
+    ];
+
+    fn modifier(self) -> Option<char> {
+        match self {
+            SkinTone::Default => None,
+            SkinTone::Light => Some('\u{1F3FB}'),
+            SkinTone::MediumLight => Some('\u{1F3FC}'),
+            SkinTone::Medium => Some('\u{1F3FD}'),
+            SkinTone::MediumDark => Some('\u{1F3FE}'),
+            SkinTone::Dark => Some('\u{1F3FF}'),
+        }
+    }
+}
+
+/// The codepoints that accept a skin tone modifier, from the `Emoji_Modifier_Base`
+/// property of the Unicode emoji data.
+static MODIFIER_BASES: &[(char, char)] = &[
+    ('\u{261D}', '\u{261D}'),
+    ('\u{26F9}', '\u{26F9}'),
+    ('\u{270A}', '\u{270D}'),
+    ('\u{1F385}', '\u{1F385}'),
+    ('\u{1F3C2}', '\u{1F3C4}'),
+    ('\u{1F3C7}', '\u{1F3C7}'),
+    ('\u{1F3CA}', '\u{1F3CC}'),
+    ('\u{1F442}', '\u{1F443}'),
+    ('\u{1F446}', '\u{1F450}'),
+    ('\u{1F466}', '\u{1F478}'),
+    ('\u{1F47C}', '\u{1F47C}'),
+    ('\u{1F481}', '\u{1F483}'),
+    ('\u{1F485}', '\u{1F487}'),
+    ('\u{1F48F}', '\u{1F48F}'),
+    ('\u{1F491}', '\u{1F491}'),
+    ('\u{1F4AA}', '\u{1F4AA}'),
+    ('\u{1F574}', '\u{1F575}'),
+    ('\u{1F57A}', '\u{1F57A}'),
+    ('\u{1F590}', '\u{1F590}'),
+    ('\u{1F595}', '\u{1F596}'),
+    ('\u{1F645}', '\u{1F647}'),
+    ('\u{1F64B}', '\u{1F64F}'),
+    ('\u{1F6A3}', '\u{1F6A3}'),
+    ('\u{1F6B4}', '\u{1F6B6}'),
+    ('\u{1F6C0}', '\u{1F6C0}'),
+    ('\u{1F6CC}', '\u{1F6CC}'),
+    ('\u{1F90C}', '\u{1F90C}'),
+    ('\u{1F90F}', '\u{1F90F}'),
+    ('\u{1F918}', '\u{1F91F}'),
+    ('\u{1F926}', '\u{1F926}'),
+    ('\u{1F930}', '\u{1F939}'),
+    ('\u{1F93C}', '\u{1F93E}'),
+    ('\u{1F977}', '\u{1F977}'),
+    ('\u{1F9B5}', '\u{1F9B6}'),
+    ('\u{1F9B8}', '\u{1F9B9}'),
+    ('\u{1F9BB}', '\u{1F9BB}'),
+    ('\u{1F9CD}', '\u{1F9CF}'),
+    ('\u{1F9D1}', '\u{1F9DD}'),
+];
+
+/// The zero width joiner between the emojis of a sequence.
+const ZWJ: char = '\u{200D}';
+
+/// The handshake between the people of a sequence, toned only on its own.
+const HANDSHAKE: char = '\u{1F91D}';
+
+/// Whether the emoji starts with a codepoint that takes a skin tone modifier.
+pub(crate) fn is_modifiable(glyph: &str) -> bool {
+    glyph.chars().next().is_some_and(is_modifier_base)
+}
+
+fn is_modifier_base(c: char) -> bool {
+    MODIFIER_BASES.iter().any(|&(start, end)| (start..=end).contains(&c))
+}
+
+/// Whether people are joined straight to each other in `glyph`, as in a family, rather
+/// than by a heart or a handshake. No toned families can be typed or drawn.
+fn is_family(glyph: &str) -> bool {
+    let chars = glyph.chars().filter(|&c| c != '\u{FE0F}').collect::<Vec<_>>();
+    let is_person = |c| is_modifier_base(c) && c != HANDSHAKE;
+    chars
+        .windows(3)
+        .any(|people| is_person(people[0]) && people[1] == ZWJ && is_person(people[2]))
+}
+
+/// Applies `tone` to the emoji, to each person of a sequence like a couple. The modifier
+/// replaces the variation selector that may follow the person. Families are left as they
+/// are.
+pub fn with_skin_tone(glyph: &str, tone: SkinTone) -> Cow<'_, str> {
+    let modifier = match tone.modifier() {
+        Some(modifier) if is_modifiable(glyph) && !is_family(glyph) => modifier,
+        _ => return Cow::Borrowed(glyph),
+    };
+    let mut toned = String::with_capacity(glyph.len() + 8);
+    let mut chars = glyph.chars().peekable();
+    let mut joined = false;
+    while let Some(c) = chars.next() {
+        toned.push(c);
+        if is_modifier_base(c) && !(joined && c == HANDSHAKE) {
+            toned.push(modifier);
+            chars.next_if_eq(&'\u{FE0F}');
+        }
+        joined = c == ZWJ;
+    }
+    Cow::Owned(toned)
+}
+
+/// Descriptions that differ from the shortcode GitHub, Slack and Discord know the emoji
+/// by.
+static SHORTCODES: &[(&str, &str)] = &[
+    ("zany", "zany_face"),
+    ("monocle", "monocle_face"),
+    ("shushing", "shushing_face"),
+    ("symbols_over_mouth", "cursing_face"),
+    ("pleading", "pleading_face"),
+    ("hot", "hot_face"),
+    ("cold", "cold_face"),
+    ("woozy", "woozy_face"),
+    ("partying", "partying_face"),
+    ("vomiting", "vomiting_face"),
+    ("palms_up", "palms_up_together"),
+    ("love_you", "love_you_gesture"),
+    ("labcoat", "lab_coat"),
+    ("woman_elf", "elf_woman"),
+    ("man_elf", "elf_man"),
+    ("woman_vampire", "vampire_woman"),
+    ("man_vampire", "vampire_man"),
+    ("woman_zombie", "zombie_woman"),
+    ("man_zombie", "zombie_man"),
+    ("woman_genie", "genie_woman"),
+    ("man_genie", "genie_man"),
+    ("woman_fairy", "fairy_woman"),
+    ("man_fairy", "fairy_man"),
+    ("woman_superhero", "superhero_woman"),
+    ("man_superhero", "superhero_man"),
+    ("woman_supervillain", "supervillain_woman"),
+    ("man_supervillain", "supervillain_man"),
+    ("woman_in_lotus_position", "lotus_position_woman"),
+    ("man_in_lotus_position", "lotus_position_man"),
+    ("woman_in_steamy_room", "sauna_woman"),
+    ("man_in_steamy_room", "sauna_man"),
+    ("leafy_greens", "leafy_green"),
+];
+
+/// The canonical `:shortcode:` name of the emoji with the description `name`.
+pub fn shortcode(name: &str) -> &str {
+    SHORTCODES.iter().find(|(desc, _)| *desc == name).map_or(name, |(_, code)| code)
+}
+
+/// Other words people commonly search for an emoji by, keyed by description.
+const KEYWORDS: &[(&str, &[&str])] = &[
+    ("joy", &["lol", "laugh", "tears"]),
+    ("rofl", &["lol", "laugh", "rolling"]),
+    ("smile", &["happy"]),
+    ("sweat_smile", &["phew", "nervous"]),
+    ("wink", &["flirt"]),
+    ("heart_eyes", &["love", "crush"]),
+    ("kissing_heart", &["kiss", "love"]),
+    ("sunglasses", &["cool"]),
+    ("star_struck", &["wow", "amazing"]),
+    ("partying", &["party", "celebrate", "birthday"]),
+    ("thinking", &["hmm", "wonder"]),
+    ("flushed", &["embarrassed", "blush"]),
+    ("grimacing", &["awkward", "yikes"]),
+    ("neutral_face", &["meh"]),
+    ("sob", &["cry", "sad"]),
+    ("zzz", &["sleep", "tired"]),
+    ("poop", &["poo", "hankey", "crap"]),
+    ("skull", &["dead", "dying"]),
+    ("raised_hands", &["hooray", "yay"]),
+    ("clap", &["applause", "bravo"]),
+    ("wave", &["hello", "hi", "bye"]),
+    ("+1", &["thumbsup", "like", "yes", "approve"]),
+    ("ok_hand", &["perfect", "okay"]),
+    ("muscle", &["strong", "flex"]),
+    ("pray", &["please", "thanks", "hope"]),
+    ("eyes", &["look", "see", "watching"]),
+    ("man_facepalming", &["facepalm", "ugh"]),
+    ("woman_facepalming", &["facepalm", "ugh"]),
+    ("woman_shrugging", &["shrug", "whatever", "idk"]),
+    ("man_shrugging", &["shrug", "whatever", "idk"]),
+    ("see_no_evil", &["monkey", "oops"]),
+    ("sparkles", &["shiny", "new", "magic"]),
+    ("fire", &["hot", "lit", "flame"]),
+    ("rocket", &["launch", "ship"]),
+    ("tada", &["party", "congrats", "celebrate"]),
+    ("heart", &["love"]),
+    ("100", &["hundred", "perfect", "score"]),
+    // Flags only known by their country code or an older name.
+    ("cn", &["china"]),
+    ("de", &["germany"]),
+    ("es", &["spain"]),
+    ("eu", &["european union"]),
+    ("fr", &["france"]),
+    ("it", &["italy"]),
+    ("jp", &["japan"]),
+    ("kr", &["south korea"]),
+    ("ru", &["russia"]),
+    ("tr", &["turkey"]),
+    ("uk", &["united kingdom", "great britain"]),
+    ("us", &["united states", "america", "usa"]),
+    ("cape_verde", &["cabo verde"]),
+    ("congo_kinshasa", &["democratic republic of the congo", "drc"]),
+    ("cote_divoire", &["ivory coast", "côte d’ivoire"]),
+    ("czech_republic", &["czechia"]),
+    ("macedonia", &["north macedonia"]),
+    ("myanmar", &["burma"]),
+    ("swaziland", &["eswatini"]),
+    ("timor_leste", &["east timor"]),
+    ("vatican_city", &["holy see"]),
+    // Native spellings, for anyone typing the accents.
+    ("coffee", &["café"]),
+    ("aland_islands", &["åland islands"]),
+    ("curacao", &["curaçao"]),
+    ("reunion", &["réunion"]),
+    ("st_barthelemy", &["saint barthélemy"]),
+    ("sao_tome_principe", &["são tomé and príncipe"]),
+];
+
+/// The CLDR subgroup of each emoji, keyed by description. They are those of Emoji 13.0,
+/// the latest emojis here, so the hearts are still under "emotion".
+static SUBGROUPS: &[(&str, &str)] = &[
+    ("grinning", "face-smiling"),
+    ("grimacing", "face-neutral-skeptical"),
+    ("grin", "face-smiling"),
+    ("joy", "face-smiling"),
+    ("rofl", "face-smiling"),
+    ("partying", "face-hat"),
+    ("smiley", "face-smiling"),
+    ("smile", "face-smiling"),
+    ("sweat_smile", "face-smiling"),
+    ("laughing", "face-smiling"),
+    ("innocent", "face-smiling"),
+    ("wink", "face-smiling"),
+    ("blush", "face-smiling"),
+    ("slightly_smiling_face", "face-smiling"),
+    ("upside_down_face", "face-smiling"),
+    ("relaxed", "face-affection"),
+    ("yum", "face-tongue"),
+    ("relieved", "face-sleepy"),
+    ("heart_eyes", "face-affection"),
+    ("smiling_face_with_three_hearts", "face-affection"),
+    ("kissing_heart", "face-affection"),
+    ("kissing", "face-affection"),
+    ("kissing_smiling_eyes", "face-affection"),
+    ("kissing_closed_eyes", "face-affection"),
+    ("stuck_out_tongue_winking_eye", "face-tongue"),
+    ("zany", "face-tongue"),
+    ("raised_eyebrow", "face-neutral-skeptical"),
+    ("monocle", "face-glasses"),
+    ("stuck_out_tongue_closed_eyes", "face-tongue"),
+    ("stuck_out_tongue", "face-tongue"),
+    ("money_mouth_face", "face-tongue"),
+    ("nerd_face", "face-glasses"),
+    ("sunglasses", "face-glasses"),
+    ("star_struck", "face-affection"),
+    ("clown_face", "face-costume"),
+    ("cowboy_hat_face", "face-hat"),
+    ("hugs", "face-hand"),
+    ("smirk", "face-neutral-skeptical"),
+    ("no_mouth", "face-neutral-skeptical"),
+    ("neutral_face", "face-neutral-skeptical"),
+    ("expressionless", "face-neutral-skeptical"),
+    ("unamused", "face-neutral-skeptical"),
+    ("roll_eyes", "face-neutral-skeptical"),
+    ("thinking", "face-hand"),
+    ("lying_face", "face-neutral-skeptical"),
+    ("hand_over_mouth", "face-hand"),
+    ("shushing", "face-hand"),
+    ("symbols_over_mouth", "face-negative"),
+    ("exploding_head", "face-unwell"),
+    ("flushed", "face-concerned"),
+    ("disappointed", "face-concerned"),
+    ("worried", "face-concerned"),
+    ("angry", "face-negative"),
+    ("rage", "face-negative"),
+    ("pensive", "face-sleepy"),
+    ("confused", "face-concerned"),
+    ("slightly_frowning_face", "face-concerned"),
+    ("frowning_face", "face-concerned"),
+    ("persevere", "face-concerned"),
+    ("confounded", "face-concerned"),
+    ("tired_face", "face-concerned"),
+    ("weary", "face-concerned"),
+    ("pleading", "face-concerned"),
+    ("triumph", "face-negative"),
+    ("open_mouth", "face-concerned"),
+    ("scream", "face-concerned"),
+    ("fearful", "face-concerned"),
+    ("cold_sweat", "face-concerned"),
+    ("hushed", "face-concerned"),
+    ("frowning", "face-concerned"),
+    ("anguished", "face-concerned"),
+    ("cry", "face-concerned"),
+    ("disappointed_relieved", "face-concerned"),
+    ("drooling_face", "face-sleepy"),
+    ("sleepy", "face-sleepy"),
+    ("sweat", "face-concerned"),
+    ("hot", "face-unwell"),
+    ("cold", "face-unwell"),
+    ("sob", "face-concerned"),
+    ("dizzy_face", "face-unwell"),
+    ("astonished", "face-concerned"),
+    ("zipper_mouth_face", "face-neutral-skeptical"),
+    ("nauseated_face", "face-unwell"),
+    ("sneezing_face", "face-unwell"),
+    ("vomiting", "face-unwell"),
+    ("mask", "face-unwell"),
+    ("face_with_thermometer", "face-unwell"),
+    ("face_with_head_bandage", "face-unwell"),
+    ("woozy", "face-unwell"),
+    ("sleeping", "face-sleepy"),
+    ("zzz", "emotion"),
+    ("poop", "face-costume"),
+    ("smiling_imp", "face-negative"),
+    ("imp", "face-negative"),
+    ("japanese_ogre", "face-costume"),
+    ("japanese_goblin", "face-costume"),
+    ("skull", "face-negative"),
+    ("ghost", "face-costume"),
+    ("alien", "face-costume"),
+    ("robot", "face-costume"),
+    ("smiley_cat", "cat-face"),
+    ("smile_cat", "cat-face"),
+    ("joy_cat", "cat-face"),
+    ("heart_eyes_cat", "cat-face"),
+    ("smirk_cat", "cat-face"),
+    ("kissing_cat", "cat-face"),
+    ("scream_cat", "cat-face"),
+    ("crying_cat_face", "cat-face"),
+    ("pouting_cat", "cat-face"),
+    ("palms_up", "hands"),
+    ("raised_hands", "hands"),
+    ("clap", "hands"),
+    ("wave", "hand-fingers-open"),
+    ("call_me_hand", "hand-fingers-partial"),
+    ("+1", "hand-fingers-closed"),
+    ("-1", "hand-fingers-closed"),
+    ("facepunch", "hand-fingers-closed"),
+    ("fist", "hand-fingers-closed"),
+    ("fist_left", "hand-fingers-closed"),
+    ("fist_right", "hand-fingers-closed"),
+    ("v", "hand-fingers-partial"),
+    ("ok_hand", "hand-fingers-partial"),
+    ("raised_hand", "hand-fingers-open"),
+    ("raised_back_of_hand", "hand-fingers-open"),
+    ("open_hands", "hands"),
+    ("muscle", "body-parts"),
+    ("pray", "hands"),
+    ("foot", "body-parts"),
+    ("leg", "body-parts"),
+    ("handshake", "hands"),
+    ("point_up", "hand-single-finger"),
+    ("point_up_2", "hand-single-finger"),
+    ("point_down", "hand-single-finger"),
+    ("point_left", "hand-single-finger"),
+    ("point_right", "hand-single-finger"),
+    ("fu", "hand-single-finger"),
+    ("raised_hand_with_fingers_splayed", "hand-fingers-open"),
+    ("love_you", "hand-fingers-partial"),
+    ("metal", "hand-fingers-partial"),
+    ("crossed_fingers", "hand-fingers-partial"),
+    ("vulcan_salute", "hand-fingers-open"),
+    ("writing_hand", "hand-prop"),
+    ("selfie", "hand-prop"),
+    ("nail_care", "hand-prop"),
+    ("lips", "body-parts"),
+    ("tooth", "body-parts"),
+    ("tongue", "body-parts"),
+    ("ear", "body-parts"),
+    ("nose", "body-parts"),
+    ("eye", "body-parts"),
+    ("eyes", "body-parts"),
+    ("brain", "body-parts"),
+    ("bust_in_silhouette", "person-symbol"),
+    ("busts_in_silhouette", "person-symbol"),
+    ("speaking_head", "person-symbol"),
+    ("baby", "person"),
+    ("child", "person"),
+    ("boy", "person"),
+    ("girl", "person"),
+    ("adult", "person"),
+    ("man", "person"),
+    ("woman", "person"),
+    ("blonde_woman", "person"),
+    ("blonde_man", "person"),
+    ("bearded_person", "person"),
+    ("older_adult", "person"),
+    ("older_man", "person"),
+    ("older_woman", "person"),
+    ("man_with_gua_pi_mao", "person-role"),
+    ("woman_with_headscarf", "person-role"),
+    ("woman_with_turban", "person-role"),
+    ("man_with_turban", "person-role"),
+    ("policewoman", "person-role"),
+    ("policeman", "person-role"),
+    ("construction_worker_woman", "person-role"),
+    ("construction_worker_man", "person-role"),
+    ("guardswoman", "person-role"),
+    ("guardsman", "person-role"),
+    ("female_detective", "person-role"),
+    ("male_detective", "person-role"),
+    ("woman_health_worker", "person-role"),
+    ("man_health_worker", "person-role"),
+    ("woman_farmer", "person-role"),
+    ("man_farmer", "person-role"),
+    ("woman_cook", "person-role"),
+    ("man_cook", "person-role"),
+    ("woman_student", "person-role"),
+    ("man_student", "person-role"),
+    ("woman_singer", "person-role"),
+    ("man_singer", "person-role"),
+    ("woman_teacher", "person-role"),
+    ("man_teacher", "person-role"),
+    ("woman_factory_worker", "person-role"),
+    ("man_factory_worker", "person-role"),
+    ("woman_technologist", "person-role"),
+    ("man_technologist", "person-role"),
+    ("woman_office_worker", "person-role"),
+    ("man_office_worker", "person-role"),
+    ("woman_mechanic", "person-role"),
+    ("man_mechanic", "person-role"),
+    ("woman_scientist", "person-role"),
+    ("man_scientist", "person-role"),
+    ("woman_artist", "person-role"),
+    ("man_artist", "person-role"),
+    ("woman_firefighter", "person-role"),
+    ("man_firefighter", "person-role"),
+    ("woman_pilot", "person-role"),
+    ("man_pilot", "person-role"),
+    ("woman_astronaut", "person-role"),
+    ("man_astronaut", "person-role"),
+    ("woman_judge", "person-role"),
+    ("man_judge", "person-role"),
+    ("woman_superhero", "person-fantasy"),
+    ("man_superhero", "person-fantasy"),
+    ("woman_supervillain", "person-fantasy"),
+    ("man_supervillain", "person-fantasy"),
+    ("mrs_claus", "person-fantasy"),
+    ("santa", "person-fantasy"),
+    ("sorceress", "person-fantasy"),
+    ("wizard", "person-fantasy"),
+    ("woman_elf", "person-fantasy"),
+    ("man_elf", "person-fantasy"),
+    ("woman_vampire", "person-fantasy"),
+    ("man_vampire", "person-fantasy"),
+    ("woman_zombie", "person-fantasy"),
+    ("man_zombie", "person-fantasy"),
+    ("woman_genie", "person-fantasy"),
+    ("man_genie", "person-fantasy"),
+    ("mermaid", "person-fantasy"),
+    ("merman", "person-fantasy"),
+    ("woman_fairy", "person-fantasy"),
+    ("man_fairy", "person-fantasy"),
+    ("angel", "person-fantasy"),
+    ("pregnant_woman", "person-role"),
+    ("breastfeeding", "person-role"),
+    ("princess", "person-role"),
+    ("prince", "person-role"),
+    ("bride_with_veil", "person-role"),
+    ("man_in_tuxedo", "person-role"),
+    ("running_woman", "person-activity"),
+    ("running_man", "person-activity"),
+    ("walking_woman", "person-activity"),
+    ("walking_man", "person-activity"),
+    ("dancer", "person-activity"),
+    ("man_dancing", "person-activity"),
+    ("dancing_women", "person-activity"),
+    ("dancing_men", "person-activity"),
+    ("couple", "family"),
+    ("two_men_holding_hands", "family"),
+    ("two_women_holding_hands", "family"),
+    ("bowing_woman", "person-gesture"),
+    ("bowing_man", "person-gesture"),
+    ("man_facepalming", "person-gesture"),
+    ("woman_facepalming", "person-gesture"),
+    ("woman_shrugging", "person-gesture"),
+    ("man_shrugging", "person-gesture"),
+    ("tipping_hand_woman", "person-gesture"),
+    ("tipping_hand_man", "person-gesture"),
+    ("no_good_woman", "person-gesture"),
+    ("no_good_man", "person-gesture"),
+    ("ok_woman", "person-gesture"),
+    ("ok_man", "person-gesture"),
+    ("raising_hand_woman", "person-gesture"),
+    ("raising_hand_man", "person-gesture"),
+    ("pouting_woman", "person-gesture"),
+    ("pouting_man", "person-gesture"),
+    ("frowning_woman", "person-gesture"),
+    ("frowning_man", "person-gesture"),
+    ("haircut_woman", "person-activity"),
+    ("haircut_man", "person-activity"),
+    ("massage_woman", "person-activity"),
+    ("massage_man", "person-activity"),
+    ("woman_in_steamy_room", "person-activity"),
+    ("man_in_steamy_room", "person-activity"),
+    ("couple_with_heart_woman_man", "family"),
+    ("couple_with_heart_woman_woman", "family"),
+    ("couple_with_heart_man_man", "family"),
+    ("couplekiss_man_woman", "family"),
+    ("couplekiss_woman_woman", "family"),
+    ("couplekiss_man_man", "family"),
+    ("family_man_woman_boy", "family"),
+    ("family_man_woman_girl", "family"),
+    ("family_man_woman_girl_boy", "family"),
+    ("family_man_woman_boy_boy", "family"),
+    ("family_man_woman_girl_girl", "family"),
+    ("family_woman_woman_boy", "family"),
+    ("family_woman_woman_girl", "family"),
+    ("family_woman_woman_girl_boy", "family"),
+    ("family_woman_woman_boy_boy", "family"),
+    ("family_woman_woman_girl_girl", "family"),
+    ("family_man_man_boy", "family"),
+    ("family_man_man_girl", "family"),
+    ("family_man_man_girl_boy", "family"),
+    ("family_man_man_boy_boy", "family"),
+    ("family_man_man_girl_girl", "family"),
+    ("family_woman_boy", "family"),
+    ("family_woman_girl", "family"),
+    ("family_woman_girl_boy", "family"),
+    ("family_woman_boy_boy", "family"),
+    ("family_woman_girl_girl", "family"),
+    ("family_man_boy", "family"),
+    ("family_man_girl", "family"),
+    ("family_man_girl_boy", "family"),
+    ("family_man_boy_boy", "family"),
+    ("family_man_girl_girl", "family"),
+    ("yarn", "arts & crafts"),
+    ("thread", "arts & crafts"),
+    ("coat", "clothing"),
+    ("labcoat", "clothing"),
+    ("womans_clothes", "clothing"),
+    ("tshirt", "clothing"),
+    ("jeans", "clothing"),
+    ("necktie", "clothing"),
+    ("dress", "clothing"),
+    ("bikini", "clothing"),
+    ("kimono", "clothing"),
+    ("lipstick", "clothing"),
+    ("kiss", "emotion"),
+    ("footprints", "person-symbol"),
+    ("flat_shoe", "clothing"),
+    ("high_heel", "clothing"),
+    ("sandal", "clothing"),
+    ("boot", "clothing"),
+    ("mans_shoe", "clothing"),
+    ("athletic_shoe", "clothing"),
+    ("hiking_boot", "clothing"),
+    ("socks", "clothing"),
+    ("gloves", "clothing"),
+    ("scarf", "clothing"),
+    ("womans_hat", "clothing"),
+    ("tophat", "clothing"),
+    ("billed_hat", "clothing"),
+    ("rescue_worker_helmet", "clothing"),
+    ("mortar_board", "clothing"),
+    ("crown", "clothing"),
+    ("school_satchel", "clothing"),
+    ("luggage", "hotel"),
+    ("pouch", "clothing"),
+    ("purse", "clothing"),
+    ("handbag", "clothing"),
+    ("briefcase", "office"),
+    ("eyeglasses", "clothing"),
+    ("dark_sunglasses", "clothing"),
+    ("goggles", "clothing"),
+    ("ring", "clothing"),
+    ("closed_umbrella", "sky & weather"),
+    ("dog", "animal-mammal"),
+    ("cat", "animal-mammal"),
+    ("mouse", "animal-mammal"),
+    ("hamster", "animal-mammal"),
+    ("rabbit", "animal-mammal"),
+    ("fox_face", "animal-mammal"),
+    ("bear", "animal-mammal"),
+    ("panda_face", "animal-mammal"),
+    ("koala", "animal-mammal"),
+    ("tiger", "animal-mammal"),
+    ("lion", "animal-mammal"),
+    ("cow", "animal-mammal"),
+    ("pig", "animal-mammal"),
+    ("pig_nose", "animal-mammal"),
+    ("frog", "animal-amphibian"),
+    ("squid", "food-marine"),
+    ("octopus", "animal-marine"),
+    ("shrimp", "food-marine"),
+    ("monkey_face", "animal-mammal"),
+    ("gorilla", "animal-mammal"),
+    ("see_no_evil", "monkey-face"),
+    ("hear_no_evil", "monkey-face"),
+    ("speak_no_evil", "monkey-face"),
+    ("monkey", "animal-mammal"),
+    ("chicken", "animal-bird"),
+    ("penguin", "animal-bird"),
+    ("bird", "animal-bird"),
+    ("baby_chick", "animal-bird"),
+    ("hatching_chick", "animal-bird"),
+    ("hatched_chick", "animal-bird"),
+    ("duck", "animal-bird"),
+    ("eagle", "animal-bird"),
+    ("owl", "animal-bird"),
+    ("bat", "animal-mammal"),
+    ("wolf", "animal-mammal"),
+    ("boar", "animal-mammal"),
+    ("horse", "animal-mammal"),
+    ("unicorn", "animal-mammal"),
+    ("honeybee", "animal-bug"),
+    ("bug", "animal-bug"),
+    ("butterfly", "animal-bug"),
+    ("snail", "animal-bug"),
+    ("beetle", "animal-bug"),
+    ("ant", "animal-bug"),
+    ("grasshopper", "animal-bug"),
+    ("spider", "animal-bug"),
+    ("scorpion", "animal-bug"),
+    ("crab", "food-marine"),
+    ("snake", "animal-reptile"),
+    ("lizard", "animal-reptile"),
+    ("t-rex", "animal-reptile"),
+    ("sauropod", "animal-reptile"),
+    ("turtle", "animal-reptile"),
+    ("tropical_fish", "animal-marine"),
+    ("fish", "animal-marine"),
+    ("blowfish", "animal-marine"),
+    ("dolphin", "animal-marine"),
+    ("shark", "animal-marine"),
+    ("whale", "animal-marine"),
+    ("whale2", "animal-marine"),
+    ("crocodile", "animal-reptile"),
+    ("leopard", "animal-mammal"),
+    ("zebra", "animal-mammal"),
+    ("tiger2", "animal-mammal"),
+    ("water_buffalo", "animal-mammal"),
+    ("ox", "animal-mammal"),
+    ("cow2", "animal-mammal"),
+    ("deer", "animal-mammal"),
+    ("dromedary_camel", "animal-mammal"),
+    ("camel", "animal-mammal"),
+    ("giraffe", "animal-mammal"),
+    ("elephant", "animal-mammal"),
+    ("rhinoceros", "animal-mammal"),
+    ("goat", "animal-mammal"),
+    ("ram", "animal-mammal"),
+    ("sheep", "animal-mammal"),
+    ("racehorse", "animal-mammal"),
+    ("pig2", "animal-mammal"),
+    ("rat", "animal-mammal"),
+    ("mouse2", "animal-mammal"),
+    ("rooster", "animal-bird"),
+    ("turkey", "animal-bird"),
+    ("dove", "animal-bird"),
+    ("dog2", "animal-mammal"),
+    ("poodle", "animal-mammal"),
+    ("cat2", "animal-mammal"),
+    ("rabbit2", "animal-mammal"),
+    ("chipmunk", "animal-mammal"),
+    ("hedgehog", "animal-mammal"),
+    ("raccoon", "animal-mammal"),
+    ("llama", "animal-mammal"),
+    ("hippopotamus", "animal-mammal"),
+    ("kangaroo", "animal-mammal"),
+    ("badger", "animal-mammal"),
+    ("swan", "animal-bird"),
+    ("peacock", "animal-bird"),
+    ("parrot", "animal-bird"),
+    ("lobster", "food-marine"),
+    ("mosquito", "animal-bug"),
+    ("paw_prints", "animal-mammal"),
+    ("dragon", "animal-reptile"),
+    ("dragon_face", "animal-reptile"),
+    ("cactus", "plant-other"),
+    ("christmas_tree", "event"),
+    ("evergreen_tree", "plant-other"),
+    ("deciduous_tree", "plant-other"),
+    ("palm_tree", "plant-other"),
+    ("seedling", "plant-other"),
+    ("herb", "plant-other"),
+    ("shamrock", "plant-other"),
+    ("four_leaf_clover", "plant-other"),
+    ("bamboo", "event"),
+    ("tanabata_tree", "event"),
+    ("leaves", "plant-other"),
+    ("fallen_leaf", "plant-other"),
+    ("maple_leaf", "plant-other"),
+    ("ear_of_rice", "plant-other"),
+    ("hibiscus", "plant-flower"),
+    ("sunflower", "plant-flower"),
+    ("rose", "plant-flower"),
+    ("wilted_flower", "plant-flower"),
+    ("tulip", "plant-flower"),
+    ("blossom", "plant-flower"),
+    ("cherry_blossom", "plant-flower"),
+    ("bouquet", "plant-flower"),
+    ("mushroom", "food-vegetable"),
+    ("chestnut", "food-vegetable"),
+    ("jack_o_lantern", "event"),
+    ("shell", "animal-marine"),
+    ("spider_web", "animal-bug"),
+    ("earth_americas", "place-map"),
+    ("earth_africa", "place-map"),
+    ("earth_asia", "place-map"),
+    ("full_moon", "sky & weather"),
+    ("waning_gibbous_moon", "sky & weather"),
+    ("last_quarter_moon", "sky & weather"),
+    ("waning_crescent_moon", "sky & weather"),
+    ("new_moon", "sky & weather"),
+    ("waxing_crescent_moon", "sky & weather"),
+    ("first_quarter_moon", "sky & weather"),
+    ("waxing_gibbous_moon", "sky & weather"),
+    ("new_moon_with_face", "sky & weather"),
+    ("full_moon_with_face", "sky & weather"),
+    ("first_quarter_moon_with_face", "sky & weather"),
+    ("last_quarter_moon_with_face", "sky & weather"),
+    ("sun_with_face", "sky & weather"),
+    ("crescent_moon", "sky & weather"),
+    ("star", "sky & weather"),
+    ("star2", "sky & weather"),
+    ("dizzy", "emotion"),
+    ("sparkles", "event"),
+    ("comet", "sky & weather"),
+    ("sunny", "sky & weather"),
+    ("sun_behind_small_cloud", "sky & weather"),
+    ("partly_sunny", "sky & weather"),
+    ("sun_behind_large_cloud", "sky & weather"),
+    ("sun_behind_rain_cloud", "sky & weather"),
+    ("cloud", "sky & weather"),
+    ("cloud_with_rain", "sky & weather"),
+    ("cloud_with_lightning_and_rain", "sky & weather"),
+    ("cloud_with_lightning", "sky & weather"),
+    ("zap", "sky & weather"),
+    ("fire", "sky & weather"),
+    ("boom", "emotion"),
+    ("snowflake", "sky & weather"),
+    ("cloud_with_snow", "sky & weather"),
+    ("snowman", "sky & weather"),
+    ("snowman_with_snow", "sky & weather"),
+    ("wind_face", "sky & weather"),
+    ("dash", "emotion"),
+    ("tornado", "sky & weather"),
+    ("fog", "sky & weather"),
+    ("open_umbrella", "sky & weather"),
+    ("umbrella", "sky & weather"),
+    ("droplet", "sky & weather"),
+    ("sweat_drops", "emotion"),
+    ("ocean", "sky & weather"),
+    ("green_apple", "food-fruit"),
+    ("apple", "food-fruit"),
+    ("pear", "food-fruit"),
+    ("tangerine", "food-fruit"),
+    ("lemon", "food-fruit"),
+    ("banana", "food-fruit"),
+    ("watermelon", "food-fruit"),
+    ("grapes", "food-fruit"),
+    ("strawberry", "food-fruit"),
+    ("melon", "food-fruit"),
+    ("cherries", "food-fruit"),
+    ("peach", "food-fruit"),
+    ("pineapple", "food-fruit"),
+    ("coconut", "food-fruit"),
+    ("kiwi_fruit", "food-fruit"),
+    ("mango", "food-fruit"),
+    ("avocado", "food-vegetable"),
+    ("broccoli", "food-vegetable"),
+    ("tomato", "food-fruit"),
+    ("eggplant", "food-vegetable"),
+    ("cucumber", "food-vegetable"),
+    ("carrot", "food-vegetable"),
+    ("hot_pepper", "food-vegetable"),
+    ("potato", "food-vegetable"),
+    ("corn", "food-vegetable"),
+    ("leafy_greens", "food-vegetable"),
+    ("sweet_potato", "food-asian"),
+    ("peanuts", "food-vegetable"),
+    ("honey_pot", "food-sweet"),
+    ("croissant", "food-prepared"),
+    ("bread", "food-prepared"),
+    ("baguette_bread", "food-prepared"),
+    ("bagel", "food-prepared"),
+    ("pretzel", "food-prepared"),
+    ("cheese", "food-prepared"),
+    ("egg", "food-prepared"),
+    ("bacon", "food-prepared"),
+    ("steak", "food-prepared"),
+    ("pancakes", "food-prepared"),
+    ("poultry_leg", "food-prepared"),
+    ("meat_on_bone", "food-prepared"),
+    ("bone", "body-parts"),
+    ("fried_shrimp", "food-asian"),
+    ("fried_egg", "food-prepared"),
+    ("hamburger", "food-prepared"),
+    ("fries", "food-prepared"),
+    ("stuffed_flatbread", "food-prepared"),
+    ("hotdog", "food-prepared"),
+    ("pizza", "food-prepared"),
+    ("sandwich", "food-prepared"),
+    ("canned_food", "food-prepared"),
+    ("spaghetti", "food-asian"),
+    ("taco", "food-prepared"),
+    ("burrito", "food-prepared"),
+    ("green_salad", "food-prepared"),
+    ("shallow_pan_of_food", "food-prepared"),
+    ("ramen", "food-asian"),
+    ("stew", "food-prepared"),
+    ("fish_cake", "food-asian"),
+    ("fortune_cookie", "food-asian"),
+    ("sushi", "food-asian"),
+    ("bento", "food-asian"),
+    ("curry", "food-asian"),
+    ("rice_ball", "food-asian"),
+    ("rice", "food-asian"),
+    ("rice_cracker", "food-asian"),
+    ("oden", "food-asian"),
+    ("dango", "food-asian"),
+    ("shaved_ice", "food-sweet"),
+    ("ice_cream", "food-sweet"),
+    ("icecream", "food-sweet"),
+    ("pie", "food-sweet"),
+    ("cake", "food-sweet"),
+    ("cupcake", "food-sweet"),
+    ("moon_cake", "food-asian"),
+    ("birthday", "food-sweet"),
+    ("custard", "food-sweet"),
+    ("candy", "food-sweet"),
+    ("lollipop", "food-sweet"),
+    ("chocolate_bar", "food-sweet"),
+    ("popcorn", "food-prepared"),
+    ("dumpling", "food-asian"),
+    ("doughnut", "food-sweet"),
+    ("cookie", "food-sweet"),
+    ("milk_glass", "drink"),
+    ("beer", "drink"),
+    ("beers", "drink"),
+    ("clinking_glasses", "drink"),
+    ("wine_glass", "drink"),
+    ("tumbler_glass", "drink"),
+    ("cocktail", "drink"),
+    ("tropical_drink", "drink"),
+    ("champagne", "drink"),
+    ("sake", "drink"),
+    ("tea", "drink"),
+    ("cup_with_straw", "drink"),
+    ("coffee", "drink"),
+    ("baby_bottle", "drink"),
+    ("salt", "food-prepared"),
+    ("spoon", "dishware"),
+    ("fork_and_knife", "dishware"),
+    ("plate_with_cutlery", "dishware"),
+    ("bowl_with_spoon", "food-prepared"),
+    ("takeout_box", "food-asian"),
+    ("chopsticks", "dishware"),
+    ("soccer", "sport"),
+    ("basketball", "sport"),
+    ("football", "sport"),
+    ("baseball", "sport"),
+    ("softball", "sport"),
+    ("tennis", "sport"),
+    ("volleyball", "sport"),
+    ("rugby_football", "sport"),
+    ("flying_disc", "sport"),
+    ("8ball", "game"),
+    ("golf", "sport"),
+    ("golfing_woman", "person-sport"),
+    ("golfing_man", "person-sport"),
+    ("ping_pong", "sport"),
+    ("badminton", "sport"),
+    ("goal_net", "sport"),
+    ("ice_hockey", "sport"),
+    ("field_hockey", "sport"),
+    ("lacrosse", "sport"),
+    ("cricket", "sport"),
+    ("ski", "sport"),
+    ("skier", "person-sport"),
+    ("snowboarder", "person-sport"),
+    ("person_fencing", "person-sport"),
+    ("women_wrestling", "person-sport"),
+    ("men_wrestling", "person-sport"),
+    ("woman_cartwheeling", "person-sport"),
+    ("man_cartwheeling", "person-sport"),
+    ("woman_playing_handball", "person-sport"),
+    ("man_playing_handball", "person-sport"),
+    ("ice_skate", "sport"),
+    ("curling_stone", "sport"),
+    ("skateboard", "transport-ground"),
+    ("sled", "sport"),
+    ("bow_and_arrow", "tool"),
+    ("fishing_pole_and_fish", "sport"),
+    ("boxing_glove", "sport"),
+    ("martial_arts_uniform", "sport"),
+    ("rowing_woman", "person-sport"),
+    ("rowing_man", "person-sport"),
+    ("climbing_woman", "person-activity"),
+    ("climbing_man", "person-activity"),
+    ("swimming_woman", "person-sport"),
+    ("swimming_man", "person-sport"),
+    ("woman_playing_water_polo", "person-sport"),
+    ("man_playing_water_polo", "person-sport"),
+    ("woman_in_lotus_position", "person-resting"),
+    ("man_in_lotus_position", "person-resting"),
+    ("surfing_woman", "person-sport"),
+    ("surfing_man", "person-sport"),
+    ("bath", "person-resting"),
+    ("basketball_woman", "person-sport"),
+    ("basketball_man", "person-sport"),
+    ("weight_lifting_woman", "person-sport"),
+    ("weight_lifting_man", "person-sport"),
+    ("biking_woman", "person-sport"),
+    ("biking_man", "person-sport"),
+    ("mountain_biking_woman", "person-sport"),
+    ("mountain_biking_man", "person-sport"),
+    ("horse_racing", "person-sport"),
+    ("business_suit_levitating", "person-activity"),
+    ("trophy", "award-medal"),
+    ("running_shirt_with_sash", "sport"),
+    ("medal_sports", "award-medal"),
+    ("medal_military", "award-medal"),
+    ("1st_place_medal", "award-medal"),
+    ("2nd_place_medal", "award-medal"),
+    ("3rd_place_medal", "award-medal"),
+    ("reminder_ribbon", "event"),
+    ("rosette", "plant-flower"),
+    ("ticket", "event"),
+    ("tickets", "event"),
+    ("performing_arts", "arts & crafts"),
+    ("art", "arts & crafts"),
+    ("circus_tent", "place-other"),
+    ("woman_juggling", "person-sport"),
+    ("man_juggling", "person-sport"),
+    ("microphone", "music"),
+    ("headphones", "music"),
+    ("musical_score", "music"),
+    ("musical_keyboard", "musical-instrument"),
+    ("drum", "musical-instrument"),
+    ("saxophone", "musical-instrument"),
+    ("trumpet", "musical-instrument"),
+    ("guitar", "musical-instrument"),
+    ("violin", "musical-instrument"),
+    ("clapper", "light & video"),
+    ("video_game", "game"),
+    ("space_invader", "game"),
+    ("dart", "game"),
+    ("game_die", "game"),
+    ("chess_pawn", "game"),
+    ("slot_machine", "game"),
+    ("jigsaw", "game"),
+    ("bowling", "sport"),
+    ("red_car", "transport-ground"),
+    ("taxi", "transport-ground"),
+    ("blue_car", "transport-ground"),
+    ("bus", "transport-ground"),
+    ("trolleybus", "transport-ground"),
+    ("racing_car", "transport-ground"),
+    ("police_car", "transport-ground"),
+    ("ambulance", "transport-ground"),
+    ("fire_engine", "transport-ground"),
+    ("minibus", "transport-ground"),
+    ("truck", "transport-ground"),
+    ("articulated_lorry", "transport-ground"),
+    ("tractor", "transport-ground"),
+    ("kick_scooter", "transport-ground"),
+    ("motorcycle", "transport-ground"),
+    ("bike", "transport-ground"),
+    ("motor_scooter", "transport-ground"),
+    ("rotating_light", "transport-ground"),
+    ("oncoming_police_car", "transport-ground"),
+    ("oncoming_bus", "transport-ground"),
+    ("oncoming_automobile", "transport-ground"),
+    ("oncoming_taxi", "transport-ground"),
+    ("aerial_tramway", "transport-air"),
+    ("mountain_cableway", "transport-air"),
+    ("suspension_railway", "transport-air"),
+    ("railway_car", "transport-ground"),
+    ("train", "transport-ground"),
+    ("monorail", "transport-ground"),
+    ("bullettrain_side", "transport-ground"),
+    ("bullettrain_front", "transport-ground"),
+    ("light_rail", "transport-ground"),
+    ("mountain_railway", "transport-ground"),
+    ("steam_locomotive", "transport-ground"),
+    ("train2", "transport-ground"),
+    ("metro", "transport-ground"),
+    ("tram", "transport-ground"),
+    ("station", "transport-ground"),
+    ("flying_saucer", "transport-air"),
+    ("helicopter", "transport-air"),
+    ("small_airplane", "transport-air"),
+    ("airplane", "transport-air"),
+    ("flight_departure", "transport-air"),
+    ("flight_arrival", "transport-air"),
+    ("sailboat", "transport-water"),
+    ("motor_boat", "transport-water"),
+    ("speedboat", "transport-water"),
+    ("ferry", "transport-water"),
+    ("passenger_ship", "transport-water"),
+    ("rocket", "transport-air"),
+    ("artificial_satellite", "transport-air"),
+    ("seat", "transport-air"),
+    ("canoe", "transport-water"),
+    ("anchor", "transport-water"),
+    ("construction", "transport-ground"),
+    ("fuelpump", "transport-ground"),
+    ("busstop", "transport-ground"),
+    ("vertical_traffic_light", "transport-ground"),
+    ("traffic_light", "transport-ground"),
+    ("checkered_flag", "flag"),
+    ("ship", "transport-water"),
+    ("ferris_wheel", "place-other"),
+    ("roller_coaster", "place-other"),
+    ("carousel_horse", "place-other"),
+    ("building_construction", "place-building"),
+    ("foggy", "place-other"),
+    ("tokyo_tower", "place-building"),
+    ("factory", "place-building"),
+    ("fountain", "place-other"),
+    ("rice_scene", "event"),
+    ("mountain", "place-geographic"),
+    ("mountain_snow", "place-geographic"),
+    ("mount_fuji", "place-geographic"),
+    ("volcano", "place-geographic"),
+    ("japan", "place-map"),
+    ("camping", "place-geographic"),
+    ("tent", "place-other"),
+    ("national_park", "place-geographic"),
+    ("motorway", "transport-ground"),
+    ("railway_track", "transport-ground"),
+    ("sunrise", "place-other"),
+    ("sunrise_over_mountains", "place-other"),
+    ("desert", "place-geographic"),
+    ("beach_umbrella", "place-geographic"),
+    ("desert_island", "place-geographic"),
+    ("city_sunrise", "place-other"),
+    ("city_sunset", "place-other"),
+    ("cityscape", "place-other"),
+    ("night_with_stars", "place-other"),
+    ("bridge_at_night", "place-other"),
+    ("milky_way", "sky & weather"),
+    ("stars", "sky & weather"),
+    ("sparkler", "event"),
+    ("fireworks", "event"),
+    ("rainbow", "sky & weather"),
+    ("houses", "place-building"),
+    ("european_castle", "place-building"),
+    ("japanese_castle", "place-building"),
+    ("stadium", "place-building"),
+    ("statue_of_liberty", "place-building"),
+    ("house", "place-building"),
+    ("house_with_garden", "place-building"),
+    ("derelict_house", "place-building"),
+    ("office", "place-building"),
+    ("department_store", "place-building"),
+    ("post_office", "place-building"),
+    ("european_post_office", "place-building"),
+    ("hospital", "place-building"),
+    ("bank", "place-building"),
+    ("hotel", "place-building"),
+    ("convenience_store", "place-building"),
+    ("school", "place-building"),
+    ("love_hotel", "place-building"),
+    ("wedding", "place-building"),
+    ("classical_building", "place-building"),
+    ("church", "place-religious"),
+    ("mosque", "place-religious"),
+    ("synagogue", "place-religious"),
+    ("kaaba", "place-religious"),
+    ("shinto_shrine", "place-religious"),
+    ("watch", "time"),
+    ("iphone", "phone"),
+    ("calling", "phone"),
+    ("computer", "computer"),
+    ("keyboard", "computer"),
+    ("desktop_computer", "computer"),
+    ("printer", "computer"),
+    ("computer_mouse", "computer"),
+    ("trackball", "computer"),
+    ("joystick", "game"),
+    ("clamp", "tool"),
+    ("minidisc", "computer"),
+    ("floppy_disk", "computer"),
+    ("cd", "computer"),
+    ("dvd", "computer"),
+    ("vhs", "light & video"),
+    ("camera", "light & video"),
+    ("camera_flash", "light & video"),
+    ("video_camera", "light & video"),
+    ("movie_camera", "light & video"),
+    ("film_projector", "light & video"),
+    ("film_strip", "light & video"),
+    ("telephone_receiver", "phone"),
+    ("phone", "phone"),
+    ("pager", "phone"),
+    ("fax", "phone"),
+    ("tv", "light & video"),
+    ("radio", "music"),
+    ("studio_microphone", "music"),
+    ("level_slider", "music"),
+    ("control_knobs", "music"),
+    ("compass", "place-map"),
+    ("stopwatch", "time"),
+    ("timer_clock", "time"),
+    ("alarm_clock", "time"),
+    ("mantelpiece_clock", "time"),
+    ("hourglass_flowing_sand", "time"),
+    ("hourglass", "time"),
+    ("satellite", "science"),
+    ("battery", "computer"),
+    ("electric_plug", "computer"),
+    ("bulb", "light & video"),
+    ("flashlight", "light & video"),
+    ("candle", "light & video"),
+    ("fire_extinguisher", "household"),
+    ("wastebasket", "office"),
+    ("oil_drum", "transport-ground"),
+    ("money_with_wings", "money"),
+    ("dollar", "money"),
+    ("yen", "money"),
+    ("euro", "money"),
+    ("pound", "money"),
+    ("moneybag", "money"),
+    ("credit_card", "money"),
+    ("gem", "clothing"),
+    ("balance_scale", "tool"),
+    ("toolbox", "tool"),
+    ("wrench", "tool"),
+    ("hammer", "tool"),
+    ("hammer_and_pick", "tool"),
+    ("hammer_and_wrench", "tool"),
+    ("pick", "tool"),
+    ("nut_and_bolt", "tool"),
+    ("gear", "tool"),
+    ("brick", "place-building"),
+    ("chains", "tool"),
+    ("magnet", "tool"),
+    ("gun", "tool"),
+    ("bomb", "emotion"),
+    ("firecracker", "event"),
+    ("hocho", "dishware"),
+    ("dagger", "tool"),
+    ("crossed_swords", "tool"),
+    ("shield", "tool"),
+    ("smoking", "other-object"),
+    ("skull_and_crossbones", "face-negative"),
+    ("coffin", "other-object"),
+    ("funeral_urn", "other-object"),
+    ("amphora", "dishware"),
+    ("crystal_ball", "game"),
+    ("prayer_beads", "clothing"),
+    ("nazar_amulet", "game"),
+    ("barber", "place-other"),
+    ("alembic", "science"),
+    ("telescope", "science"),
+    ("microscope", "science"),
+    ("hole", "emotion"),
+    ("pill", "medical"),
+    ("syringe", "medical"),
+    ("dna", "science"),
+    ("microbe", "animal-bug"),
+    ("petri_dish", "science"),
+    ("test_tube", "science"),
+    ("thermometer", "sky & weather"),
+    ("broom", "household"),
+    ("basket", "household"),
+    ("toilet_paper", "household"),
+    ("label", "book-paper"),
+    ("bookmark", "book-paper"),
+    ("toilet", "household"),
+    ("shower", "household"),
+    ("bathtub", "household"),
+    ("soap", "household"),
+    ("sponge", "household"),
+    ("lotion_bottle", "household"),
+    ("key", "lock"),
+    ("old_key", "lock"),
+    ("couch_and_lamp", "household"),
+    ("sleeping_bed", "person-resting"),
+    ("bed", "household"),
+    ("door", "household"),
+    ("bellhop_bell", "hotel"),
+    ("teddy_bear", "game"),
+    ("framed_picture", "arts & crafts"),
+    ("world_map", "place-map"),
+    ("parasol_on_ground", "sky & weather"),
+    ("moyai", "other-object"),
+    ("shopping", "clothing"),
+    ("shopping_cart", "household"),
+    ("balloon", "event"),
+    ("flags", "event"),
+    ("ribbon", "event"),
+    ("gift", "event"),
+    ("confetti_ball", "event"),
+    ("tada", "event"),
+    ("dolls", "event"),
+    ("wind_chime", "event"),
+    ("crossed_flags", "flag"),
+    ("izakaya_lantern", "light & video"),
+    ("red_envelope", "event"),
+    ("email", "mail"),
+    ("envelope_with_arrow", "mail"),
+    ("incoming_envelope", "mail"),
+    ("e-mail", "mail"),
+    ("love_letter", "emotion"),
+    ("postbox", "mail"),
+    ("mailbox_closed", "mail"),
+    ("mailbox", "mail"),
+    ("mailbox_with_mail", "mail"),
+    ("mailbox_with_no_mail", "mail"),
+    ("package", "mail"),
+    ("postal_horn", "sound"),
+    ("inbox_tray", "mail"),
+    ("outbox_tray", "mail"),
+    ("scroll", "book-paper"),
+    ("page_with_curl", "book-paper"),
+    ("bookmark_tabs", "book-paper"),
+    ("receipt", "money"),
+    ("bar_chart", "office"),
+    ("chart_with_upwards_trend", "office"),
+    ("chart_with_downwards_trend", "office"),
+    ("page_facing_up", "book-paper"),
+    ("date", "office"),
+    ("calendar", "office"),
+    ("spiral_calendar", "office"),
+    ("card_index", "office"),
+    ("card_file_box", "office"),
+    ("ballot_box", "mail"),
+    ("file_cabinet", "office"),
+    ("clipboard", "office"),
+    ("spiral_notepad", "office"),
+    ("file_folder", "office"),
+    ("open_file_folder", "office"),
+    ("card_index_dividers", "office"),
+    ("newspaper_roll", "book-paper"),
+    ("newspaper", "book-paper"),
+    ("notebook", "book-paper"),
+    ("closed_book", "book-paper"),
+    ("green_book", "book-paper"),
+    ("blue_book", "book-paper"),
+    ("orange_book", "book-paper"),
+    ("notebook_with_decorative_cover", "book-paper"),
+    ("ledger", "book-paper"),
+    ("books", "book-paper"),
+    ("open_book", "book-paper"),
+    ("safety_pin", "household"),
+    ("link", "tool"),
+    ("paperclip", "office"),
+    ("paperclips", "office"),
+    ("scissors", "office"),
+    ("triangular_ruler", "office"),
+    ("straight_ruler", "office"),
+    ("abacus", "computer"),
+    ("pushpin", "office"),
+    ("round_pushpin", "office"),
+    ("triangular_flag_on_post", "flag"),
+    ("white_flag", "flag"),
+    ("black_flag", "flag"),
+    ("rainbow_flag", "flag"),
+    ("closed_lock_with_key", "lock"),
+    ("lock", "lock"),
+    ("unlock", "lock"),
+    ("lock_with_ink_pen", "lock"),
+    ("pen", "writing"),
+    ("fountain_pen", "writing"),
+    ("black_nib", "writing"),
+    ("memo", "writing"),
+    ("pencil2", "writing"),
+    ("crayon", "writing"),
+    ("paintbrush", "writing"),
+    ("mag", "light & video"),
+    ("mag_right", "light & video"),
+    ("heart", "emotion"),
+    ("orange_heart", "emotion"),
+    ("yellow_heart", "emotion"),
+    ("green_heart", "emotion"),
+    ("blue_heart", "emotion"),
+    ("purple_heart", "emotion"),
+    ("black_heart", "emotion"),
+    ("broken_heart", "emotion"),
+    ("heavy_heart_exclamation", "emotion"),
+    ("two_hearts", "emotion"),
+    ("revolving_hearts", "emotion"),
+    ("heartbeat", "emotion"),
+    ("heartpulse", "emotion"),
+    ("sparkling_heart", "emotion"),
+    ("cupid", "emotion"),
+    ("gift_heart", "emotion"),
+    ("heart_decoration", "emotion"),
+    ("peace_symbol", "religion"),
+    ("latin_cross", "religion"),
+    ("star_and_crescent", "religion"),
+    ("om", "religion"),
+    ("wheel_of_dharma", "religion"),
+    ("star_of_david", "religion"),
+    ("six_pointed_star", "religion"),
+    ("menorah", "religion"),
+    ("yin_yang", "religion"),
+    ("orthodox_cross", "religion"),
+    ("place_of_worship", "religion"),
+    ("ophiuchus", "zodiac"),
+    ("aries", "zodiac"),
+    ("taurus", "zodiac"),
+    ("gemini", "zodiac"),
+    ("cancer", "zodiac"),
+    ("leo", "zodiac"),
+    ("virgo", "zodiac"),
+    ("libra", "zodiac"),
+    ("scorpius", "zodiac"),
+    ("sagittarius", "zodiac"),
+    ("capricorn", "zodiac"),
+    ("aquarius", "zodiac"),
+    ("pisces", "zodiac"),
+    ("id", "alphanum"),
+    ("atom_symbol", "religion"),
+    ("u7a7a", "alphanum"),
+    ("u5272", "alphanum"),
+    ("radioactive", "warning"),
+    ("biohazard", "warning"),
+    ("mobile_phone_off", "av-symbol"),
+    ("vibration_mode", "av-symbol"),
+    ("u6709", "alphanum"),
+    ("u7121", "alphanum"),
+    ("u7533", "alphanum"),
+    ("u55b6", "alphanum"),
+    ("u6708", "alphanum"),
+    ("eight_pointed_black_star", "other-symbol"),
+    ("vs", "alphanum"),
+    ("accept", "alphanum"),
+    ("white_flower", "plant-flower"),
+    ("ideograph_advantage", "alphanum"),
+    ("secret", "alphanum"),
+    ("congratulations", "alphanum"),
+    ("u5408", "alphanum"),
+    ("u6e80", "alphanum"),
+    ("u7981", "alphanum"),
+    ("a", "alphanum"),
+    ("b", "alphanum"),
+    ("ab", "alphanum"),
+    ("cl", "alphanum"),
+    ("o2", "alphanum"),
+    ("sos", "alphanum"),
+    ("no_entry", "warning"),
+    ("name_badge", "other-symbol"),
+    ("no_entry_sign", "warning"),
+    ("x", "other-symbol"),
+    ("o", "other-symbol"),
+    ("stop_sign", "transport-ground"),
+    ("anger", "emotion"),
+    ("hotsprings", "place-other"),
+    ("no_pedestrians", "warning"),
+    ("do_not_litter", "warning"),
+    ("no_bicycles", "warning"),
+    ("non-potable_water", "warning"),
+    ("underage", "warning"),
+    ("no_mobile_phones", "warning"),
+    ("exclamation", "punctuation"),
+    ("grey_exclamation", "punctuation"),
+    ("question", "punctuation"),
+    ("grey_question", "punctuation"),
+    ("bangbang", "punctuation"),
+    ("interrobang", "punctuation"),
+    ("100", "emotion"),
+    ("low_brightness", "av-symbol"),
+    ("high_brightness", "av-symbol"),
+    ("trident", "other-symbol"),
+    ("fleur_de_lis", "other-symbol"),
+    ("part_alternation_mark", "other-symbol"),
+    ("warning", "warning"),
+    ("children_crossing", "warning"),
+    ("beginner", "other-symbol"),
+    ("recycle", "other-symbol"),
+    ("u6307", "alphanum"),
+    ("chart", "money"),
+    ("sparkle", "other-symbol"),
+    ("eight_spoked_asterisk", "other-symbol"),
+    ("negative_squared_cross_mark", "other-symbol"),
+    ("white_check_mark", "other-symbol"),
+    ("diamond_shape_with_a_dot_inside", "geometric"),
+    ("cyclone", "sky & weather"),
+    ("loop", "other-symbol"),
+    ("globe_with_meridians", "place-map"),
+    ("m", "alphanum"),
+    ("atm", "transport-sign"),
+    ("sa", "alphanum"),
+    ("passport_control", "transport-sign"),
+    ("customs", "transport-sign"),
+    ("baggage_claim", "transport-sign"),
+    ("left_luggage", "transport-sign"),
+    ("wheelchair", "transport-sign"),
+    ("no_smoking", "warning"),
+    ("wc", "transport-sign"),
+    ("parking", "alphanum"),
+    ("potable_water", "transport-sign"),
+    ("mens", "transport-sign"),
+    ("womens", "transport-sign"),
+    ("baby_symbol", "transport-sign"),
+    ("restroom", "transport-sign"),
+    ("put_litter_in_its_place", "transport-sign"),
+    ("cinema", "av-symbol"),
+    ("signal_strength", "av-symbol"),
+    ("koko", "alphanum"),
+    ("ng", "alphanum"),
+    ("ok", "alphanum"),
+    ("up", "alphanum"),
+    ("cool", "alphanum"),
+    ("new", "alphanum"),
+    ("free", "alphanum"),
+    ("zero", "keycap"),
+    ("one", "keycap"),
+    ("two", "keycap"),
+    ("three", "keycap"),
+    ("four", "keycap"),
+    ("five", "keycap"),
+    ("six", "keycap"),
+    ("seven", "keycap"),
+    ("eight", "keycap"),
+    ("nine", "keycap"),
+    ("keycap_ten", "keycap"),
+    ("asterisk", "keycap"),
+    ("1234", "alphanum"),
+    ("eject_button", "av-symbol"),
+    ("arrow_forward", "av-symbol"),
+    ("pause_button", "av-symbol"),
+    ("next_track_button", "av-symbol"),
+    ("stop_button", "av-symbol"),
+    ("record_button", "av-symbol"),
+    ("play_or_pause_button", "av-symbol"),
+    ("previous_track_button", "av-symbol"),
+    ("fast_forward", "av-symbol"),
+    ("rewind", "av-symbol"),
+    ("twisted_rightwards_arrows", "av-symbol"),
+    ("repeat", "av-symbol"),
+    ("repeat_one", "av-symbol"),
+    ("arrow_backward", "av-symbol"),
+    ("arrow_up_small", "av-symbol"),
+    ("arrow_down_small", "av-symbol"),
+    ("arrow_double_up", "av-symbol"),
+    ("arrow_double_down", "av-symbol"),
+    ("arrow_right", "arrow"),
+    ("arrow_left", "arrow"),
+    ("arrow_up", "arrow"),
+    ("arrow_down", "arrow"),
+    ("arrow_upper_right", "arrow"),
+    ("arrow_lower_right", "arrow"),
+    ("arrow_lower_left", "arrow"),
+    ("arrow_upper_left", "arrow"),
+    ("arrow_up_down", "arrow"),
+    ("left_right_arrow", "arrow"),
+    ("arrows_counterclockwise", "arrow"),
+    ("arrow_right_hook", "arrow"),
+    ("leftwards_arrow_with_hook", "arrow"),
+    ("arrow_heading_up", "arrow"),
+    ("arrow_heading_down", "arrow"),
+    ("hash", "keycap"),
+    ("information_source", "alphanum"),
+    ("abc", "alphanum"),
+    ("abcd", "alphanum"),
+    ("capital_abcd", "alphanum"),
+    ("symbols", "alphanum"),
+    ("musical_note", "music"),
+    ("notes", "music"),
+    ("wavy_dash", "punctuation"),
+    ("curly_loop", "other-symbol"),
+    ("heavy_check_mark", "other-symbol"),
+    ("arrows_clockwise", "arrow"),
+    ("heavy_plus_sign", "math"),
+    ("heavy_minus_sign", "math"),
+    ("heavy_division_sign", "math"),
+    ("heavy_multiplication_x", "math"),
+    ("infinity", "math"),
+    ("heavy_dollar_sign", "currency"),
+    ("currency_exchange", "currency"),
+    ("copyright", "other-symbol"),
+    ("registered", "other-symbol"),
+    ("tm", "other-symbol"),
+    ("end", "arrow"),
+    ("back", "arrow"),
+    ("on", "arrow"),
+    ("top", "arrow"),
+    ("soon", "arrow"),
+    ("ballot_box_with_check", "other-symbol"),
+    ("radio_button", "geometric"),
+    ("white_circle", "geometric"),
+    ("black_circle", "geometric"),
+    ("red_circle", "geometric"),
+    ("large_blue_circle", "geometric"),
+    ("small_orange_diamond", "geometric"),
+    ("small_blue_diamond", "geometric"),
+    ("large_orange_diamond", "geometric"),
+    ("large_blue_diamond", "geometric"),
+    ("small_red_triangle", "geometric"),
+    ("black_small_square", "geometric"),
+    ("white_small_square", "geometric"),
+    ("black_large_square", "geometric"),
+    ("white_large_square", "geometric"),
+    ("small_red_triangle_down", "geometric"),
+    ("black_medium_square", "geometric"),
+    ("white_medium_square", "geometric"),
+    ("black_medium_small_square", "geometric"),
+    ("white_medium_small_square", "geometric"),
+    ("black_square_button", "geometric"),
+    ("white_square_button", "geometric"),
+    ("speaker", "sound"),
+    ("sound", "sound"),
+    ("loud_sound", "sound"),
+    ("mute", "sound"),
+    ("mega", "sound"),
+    ("loudspeaker", "sound"),
+    ("bell", "sound"),
+    ("no_bell", "sound"),
+    ("black_joker", "game"),
+    ("mahjong", "game"),
+    ("spades", "game"),
+    ("clubs", "game"),
+    ("hearts", "game"),
+    ("diamonds", "game"),
+    ("flower_playing_cards", "game"),
+    ("thought_balloon", "emotion"),
+    ("right_anger_bubble", "emotion"),
+    ("speech_balloon", "emotion"),
+    ("left_speech_bubble", "emotion"),
+    ("clock1", "time"),
+    ("clock2", "time"),
+    ("clock3", "time"),
+    ("clock4", "time"),
+    ("clock5", "time"),
+    ("clock6", "time"),
+    ("clock7", "time"),
+    ("clock8", "time"),
+    ("clock9", "time"),
+    ("clock10", "time"),
+    ("clock11", "time"),
+    ("clock12", "time"),
+    ("clock130", "time"),
+    ("clock230", "time"),
+    ("clock330", "time"),
+    ("clock430", "time"),
+    ("clock530", "time"),
+    ("clock630", "time"),
+    ("clock730", "time"),
+    ("clock830", "time"),
+    ("clock930", "time"),
+    ("clock1030", "time"),
+    ("clock1130", "time"),
+    ("clock1230", "time"),
+    ("afghanistan", "country-flag"),
+    ("aland_islands", "country-flag"),
+    ("albania", "country-flag"),
+    ("algeria", "country-flag"),
+    ("american_samoa", "country-flag"),
+    ("andorra", "country-flag"),
+    ("angola", "country-flag"),
+    ("anguilla", "country-flag"),
+    ("antarctica", "country-flag"),
+    ("antigua_barbuda", "country-flag"),
+    ("argentina", "country-flag"),
+    ("armenia", "country-flag"),
+    ("aruba", "country-flag"),
+    ("ascension_island", "country-flag"),
+    ("australia", "country-flag"),
+    ("austria", "country-flag"),
+    ("azerbaijan", "country-flag"),
+    ("bahamas", "country-flag"),
+    ("bahrain", "country-flag"),
+    ("bangladesh", "country-flag"),
+    ("barbados", "country-flag"),
+    ("belarus", "country-flag"),
+    ("belgium", "country-flag"),
+    ("belize", "country-flag"),
+    ("benin", "country-flag"),
+    ("bermuda", "country-flag"),
+    ("bhutan", "country-flag"),
+    ("bolivia", "country-flag"),
+    ("caribbean_netherlands", "country-flag"),
+    ("bosnia_herzegovina", "country-flag"),
+    ("botswana", "country-flag"),
+    ("bouvet_island", "country-flag"),
+    ("brazil", "country-flag"),
+    ("british_indian_ocean_territory", "country-flag"),
+    ("british_virgin_islands", "country-flag"),
+    ("brunei", "country-flag"),
+    ("bulgaria", "country-flag"),
+    ("burkina_faso", "country-flag"),
+    ("burundi", "country-flag"),
+    ("cape_verde", "country-flag"),
+    ("cambodia", "country-flag"),
+    ("cameroon", "country-flag"),
+    ("canada", "country-flag"),
+    ("canary_islands", "country-flag"),
+    ("cayman_islands", "country-flag"),
+    ("central_african_republic", "country-flag"),
+    ("ceuta_melilla", "country-flag"),
+    ("chad", "country-flag"),
+    ("chile", "country-flag"),
+    ("cn", "country-flag"),
+    ("christmas_island", "country-flag"),
+    ("clipperton_island", "country-flag"),
+    ("cocos_islands", "country-flag"),
+    ("colombia", "country-flag"),
+    ("comoros", "country-flag"),
+    ("congo_brazzaville", "country-flag"),
+    ("congo_kinshasa", "country-flag"),
+    ("cook_islands", "country-flag"),
+    ("costa_rica", "country-flag"),
+    ("croatia", "country-flag"),
+    ("cuba", "country-flag"),
+    ("curacao", "country-flag"),
+    ("cyprus", "country-flag"),
+    ("czech_republic", "country-flag"),
+    ("denmark", "country-flag"),
+    ("diego_garcia", "country-flag"),
+    ("djibouti", "country-flag"),
+    ("dominica", "country-flag"),
+    ("dominican_republic", "country-flag"),
+    ("ecuador", "country-flag"),
+    ("egypt", "country-flag"),
+    ("el_salvador", "country-flag"),
+    ("equatorial_guinea", "country-flag"),
+    ("eritrea", "country-flag"),
+    ("estonia", "country-flag"),
+    ("ethiopia", "country-flag"),
+    ("eu", "country-flag"),
+    ("falkland_islands", "country-flag"),
+    ("faroe_islands", "country-flag"),
+    ("fiji", "country-flag"),
+    ("finland", "country-flag"),
+    ("fr", "country-flag"),
+    ("french_guiana", "country-flag"),
+    ("french_polynesia", "country-flag"),
+    ("french_southern_territories", "country-flag"),
+    ("gabon", "country-flag"),
+    ("gambia", "country-flag"),
+    ("georgia", "country-flag"),
+    ("de", "country-flag"),
+    ("ghana", "country-flag"),
+    ("gibraltar", "country-flag"),
+    ("greece", "country-flag"),
+    ("greenland", "country-flag"),
+    ("grenada", "country-flag"),
+    ("guadeloupe", "country-flag"),
+    ("guam", "country-flag"),
+    ("guatemala", "country-flag"),
+    ("guernsey", "country-flag"),
+    ("guinea", "country-flag"),
+    ("guinea_bissau", "country-flag"),
+    ("guyana", "country-flag"),
+    ("haiti", "country-flag"),
+    ("heard_mcdonald_islands", "country-flag"),
+    ("honduras", "country-flag"),
+    ("hong_kong", "country-flag"),
+    ("hungary", "country-flag"),
+    ("iceland", "country-flag"),
+    ("india", "country-flag"),
+    ("indonesia", "country-flag"),
+    ("iran", "country-flag"),
+    ("iraq", "country-flag"),
+    ("ireland", "country-flag"),
+    ("isle_of_man", "country-flag"),
+    ("israel", "country-flag"),
+    ("it", "country-flag"),
+    ("cote_divoire", "country-flag"),
+    ("jamaica", "country-flag"),
+    ("jp", "country-flag"),
+    ("jersey", "country-flag"),
+    ("jordan", "country-flag"),
+    ("kazakhstan", "country-flag"),
+    ("kenya", "country-flag"),
+    ("kiribati", "country-flag"),
+    ("kosovo", "country-flag"),
+    ("kuwait", "country-flag"),
+    ("kyrgyzstan", "country-flag"),
+    ("laos", "country-flag"),
+    ("latvia", "country-flag"),
+    ("lebanon", "country-flag"),
+    ("lesotho", "country-flag"),
+    ("liberia", "country-flag"),
+    ("libya", "country-flag"),
+    ("liechtenstein", "country-flag"),
+    ("lithuania", "country-flag"),
+    ("luxembourg", "country-flag"),
+    ("macau", "country-flag"),
+    ("macedonia", "country-flag"),
+    ("madagascar", "country-flag"),
+    ("malawi", "country-flag"),
+    ("malaysia", "country-flag"),
+    ("maldives", "country-flag"),
+    ("mali", "country-flag"),
+    ("malta", "country-flag"),
+    ("marshall_islands", "country-flag"),
+    ("martinique", "country-flag"),
+    ("mauritania", "country-flag"),
+    ("mauritius", "country-flag"),
+    ("mayotte", "country-flag"),
+    ("mexico", "country-flag"),
+    ("micronesia", "country-flag"),
+    ("moldova", "country-flag"),
+    ("monaco", "country-flag"),
+    ("mongolia", "country-flag"),
+    ("montenegro", "country-flag"),
+    ("montserrat", "country-flag"),
+    ("morocco", "country-flag"),
+    ("mozambique", "country-flag"),
+    ("myanmar", "country-flag"),
+    ("namibia", "country-flag"),
+    ("nauru", "country-flag"),
+    ("nepal", "country-flag"),
+    ("netherlands", "country-flag"),
+    ("new_caledonia", "country-flag"),
+    ("new_zealand", "country-flag"),
+    ("nicaragua", "country-flag"),
+    ("niger", "country-flag"),
+    ("nigeria", "country-flag"),
+    ("niue", "country-flag"),
+    ("norfolk_island", "country-flag"),
+    ("northern_mariana_islands", "country-flag"),
+    ("north_korea", "country-flag"),
+    ("norway", "country-flag"),
+    ("oman", "country-flag"),
+    ("pakistan", "country-flag"),
+    ("palau", "country-flag"),
+    ("palestinian_territories", "country-flag"),
+    ("panama", "country-flag"),
+    ("papua_new_guinea", "country-flag"),
+    ("paraguay", "country-flag"),
+    ("peru", "country-flag"),
+    ("philippines", "country-flag"),
+    ("pitcairn_islands", "country-flag"),
+    ("poland", "country-flag"),
+    ("portugal", "country-flag"),
+    ("puerto_rico", "country-flag"),
+    ("qatar", "country-flag"),
+    ("reunion", "country-flag"),
+    ("romania", "country-flag"),
+    ("ru", "country-flag"),
+    ("rwanda", "country-flag"),
+    ("st_barthelemy", "country-flag"),
+    ("st_helena", "country-flag"),
+    ("st_kitts_nevis", "country-flag"),
+    ("st_lucia", "country-flag"),
+    ("st_martin", "country-flag"),
+    ("st_pierre_miquelon", "country-flag"),
+    ("st_vincent_grenadines", "country-flag"),
+    ("samoa", "country-flag"),
+    ("san_marino", "country-flag"),
+    ("sao_tome_principe", "country-flag"),
+    ("saudi_arabia", "country-flag"),
+    ("senegal", "country-flag"),
+    ("serbia", "country-flag"),
+    ("seychelles", "country-flag"),
+    ("sierra_leone", "country-flag"),
+    ("singapore", "country-flag"),
+    ("sint_maarten", "country-flag"),
+    ("slovakia", "country-flag"),
+    ("slovenia", "country-flag"),
+    ("solomon_islands", "country-flag"),
+    ("somalia", "country-flag"),
+    ("south_africa", "country-flag"),
+    ("south_georgia_south_sandwich_islands", "country-flag"),
+    ("kr", "country-flag"),
+    ("south_sudan", "country-flag"),
+    ("es", "country-flag"),
+    ("sri_lanka", "country-flag"),
+    ("sudan", "country-flag"),
+    ("suriname", "country-flag"),
+    ("svalbard_jan_mayen", "country-flag"),
+    ("swaziland", "country-flag"),
+    ("sweden", "country-flag"),
+    ("switzerland", "country-flag"),
+    ("syria", "country-flag"),
+    ("taiwan", "country-flag"),
+    ("tajikistan", "country-flag"),
+    ("tanzania", "country-flag"),
+    ("thailand", "country-flag"),
+    ("timor_leste", "country-flag"),
+    ("togo", "country-flag"),
+    ("tokelau", "country-flag"),
+    ("tonga", "country-flag"),
+    ("trinidad_tobago", "country-flag"),
+    ("tristan_da_cunha", "country-flag"),
+    ("tunisia", "country-flag"),
+    ("tr", "country-flag"),
+    ("turkmenistan", "country-flag"),
+    ("turks_caicos_islands", "country-flag"),
+    ("tuvalu", "country-flag"),
+    ("uganda", "country-flag"),
+    ("ukraine", "country-flag"),
+    ("united_arab_emirates", "country-flag"),
+    ("uk", "country-flag"),
+    ("england", "subdivision-flag"),
+    ("scotland", "subdivision-flag"),
+    ("wales", "subdivision-flag"),
+    ("us", "country-flag"),
+    ("us_outlying_islands", "country-flag"),
+    ("us_virgin_islands", "country-flag"),
+    ("uruguay", "country-flag"),
+    ("uzbekistan", "country-flag"),
+    ("vanuatu", "country-flag"),
+    ("vatican_city", "country-flag"),
+    ("venezuela", "country-flag"),
+    ("vietnam", "country-flag"),
+    ("wallis_futuna", "country-flag"),
+    ("western_sahara", "country-flag"),
+    ("yemen", "country-flag"),
+    ("zambia", "country-flag"),
+    ("zimbabwe", "country-flag"),
+    ("united_nations", "country-flag"),
+    ("pirate_flag", "flag"),
+];
+
+/// Whether `search` names one of the CLDR subgroups like "face-smiling", or the start of
+/// some like "hand-fingers". A single word like "heart" or "time" is searched as text
+/// even where a subgroup is called that.
+#[doc(hidden)]
+pub fn is_subgroup(search: &str) -> bool {
+    search.contains(['-', ' '])
+        && SUBGROUPS.iter().any(|(_, subgroup)| in_subgroup(subgroup, search))
+}
+
+/// Whether `subgroup` is the one `search` names or comes under it, as
+/// "hand-fingers-open" comes under "hand-fingers".
+pub(crate) fn in_subgroup(subgroup: &str, search: &str) -> bool {
+    subgroup.get(..search.len()).is_some_and(|start| start.eq_ignore_ascii_case(search))
+        && matches!(subgroup.as_bytes().get(search.len()), None | Some(b'-'))
+}
+
+/// The Emoji version each range of codepoints was added in, from Unicode 9 on. Anything
+/// older counts as 1.0, which every color emoji font draws.
+static VERSIONS: &[(u32, u32, f64)] = &[
+    // Emoji 3.0, Unicode 9.
+    (0x1F57A, 0x1F57A, 3.0),
+    (0x1F5A4, 0x1F5A4, 3.0),
+    (0x1F6D1, 0x1F6D2, 3.0),
+    (0x1F6F4, 0x1F6F6, 3.0),
+    (0x1F919, 0x1F91E, 3.0),
+    (0x1F920, 0x1F927, 3.0),
+    (0x1F930, 0x1F930, 3.0),
+    (0x1F933, 0x1F93E, 3.0),
+    (0x1F940, 0x1F94B, 3.0),
+    (0x1F950, 0x1F95E, 3.0),
+    (0x1F985, 0x1F991, 3.0),
+    // Emoji 5.0, Unicode 10. The tags spell out the flags of England, Scotland and
+    // Wales.
+    (0x1F6F7, 0x1F6F8, 5.0),
+    (0x1F91F, 0x1F91F, 5.0),
+    (0x1F928, 0x1F92F, 5.0),
+    (0x1F931, 0x1F932, 5.0),
+    (0x1F94C, 0x1F94C, 5.0),
+    (0x1F95F, 0x1F96B, 5.0),
+    (0x1F992, 0x1F997, 5.0),
+    (0x1F9D0, 0x1F9E6, 5.0),
+    (0xE0020, 0xE007F, 5.0),
+    // Emoji 11.0.
+    (0x265F, 0x265F, 11.0),
+    (0x267E, 0x267E, 11.0),
+    (0x1F6F9, 0x1F6F9, 11.0),
+    (0x1F94D, 0x1F94F, 11.0),
+    (0x1F96C, 0x1F970, 11.0),
+    (0x1F973, 0x1F976, 11.0),
+    (0x1F97A, 0x1F97A, 11.0),
+    (0x1F97C, 0x1F97F, 11.0),
+    (0x1F998, 0x1F9A2, 11.0),
+    (0x1F9B0, 0x1F9B9, 11.0),
+    (0x1F9C1, 0x1F9C2, 11.0),
+    (0x1F9E7, 0x1F9FF, 11.0),
+    // Emoji 12.0.
+    (0x1F6D5, 0x1F6D5, 12.0),
+    (0x1F6FA, 0x1F6FA, 12.0),
+    (0x1F7E0, 0x1F7EB, 12.0),
+    (0x1F90D, 0x1F90F, 12.0),
+    (0x1F93F, 0x1F93F, 12.0),
+    (0x1F971, 0x1F971, 12.0),
+    (0x1F97B, 0x1F97B, 12.0),
+    (0x1F9A5, 0x1F9AA, 12.0),
+    (0x1F9AE, 0x1F9AF, 12.0),
+    (0x1F9BA, 0x1F9BF, 12.0),
+    (0x1F9C3, 0x1F9CA, 12.0),
+    (0x1F9CD, 0x1F9CF, 12.0),
+    (0x1FA70, 0x1FA73, 12.0),
+    (0x1FA78, 0x1FA7A, 12.0),
+    (0x1FA80, 0x1FA82, 12.0),
+    (0x1FA90, 0x1FA95, 12.0),
+    // Emoji 13.0.
+    (0x1F6D6, 0x1F6D7, 13.0),
+    (0x1F6FB, 0x1F6FC, 13.0),
+    (0x1F90C, 0x1F90C, 13.0),
+    (0x1F972, 0x1F972, 13.0),
+    (0x1F977, 0x1F978, 13.0),
+    (0x1F9A3, 0x1F9A4, 13.0),
+    (0x1F9AB, 0x1F9AD, 13.0),
+    (0x1F9CB, 0x1F9CB, 13.0),
+    (0x1FA74, 0x1FA74, 13.0),
+    (0x1FA83, 0x1FA86, 13.0),
+    (0x1FA96, 0x1FAA8, 13.0),
+    (0x1FAB0, 0x1FAB6, 13.0),
+    (0x1FAC0, 0x1FAC2, 13.0),
+    (0x1FAD0, 0x1FAD6, 13.0),
+];
+
+/// Joined sequences that came later than the emojis they're made of.
+static SEQUENCE_VERSIONS: &[(&str, f64)] = &[("pirate_flag", 11.0)];
+
+/// The extra search words for the emoji described by `name`, often none.
+///
+/// This runs while `EMOJIS` is being built so it has to make do with what `const fn`s can
+/// do.
+const fn keywords(name: &str) -> &'static [&'static str] {
+    let mut i = 0;
+    while i < KEYWORDS.len() {
+        let (desc, words) = KEYWORDS[i];
+        if str_eq(desc, name) {
+            return words;
+        }
+        i += 1;
+    }
+    &[]
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// An emoji and what is known about it.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "druid", derive(Data))]
+pub struct Emoji {
+    /// The text description, also what it is looked up and saved by.
+    pub name: &'static str,
+    pub glyph: &'static str,
+    pub group: Category,
+    /// Other words it is found by besides its name.
+    #[cfg_attr(feature = "druid", data(ignore))]
+    pub keywords: &'static [&'static str],
+}
+
+impl Emoji {
+    /// The finer CLDR subgroup it is in, like "hand-fingers-open", empty if it isn't
+    /// sorted into one.
+    pub fn subgroup(&self) -> &'static str {
+        SUBGROUPS.iter().find(|(name, _)| *name == self.name).map_or("", |(_, sub)| sub)
+    }
+
+    /// The Emoji version it was added in, a font older than that draws it as a box.
+    pub fn version(&self) -> f64 {
+        if let Some((_, version)) =
+            SEQUENCE_VERSIONS.iter().find(|(name, _)| *name == self.name)
+        {
+            return *version;
+        }
+        let added = |c: char| {
+            VERSIONS
+                .iter()
+                .find(|(first, last, _)| (*first..=*last).contains(&(c as u32)))
+                .map_or(1.0, |(_, _, version)| *version)
+        };
+        let newest = self.glyph.chars().map(added).fold(1.0, f64::max);
+        if !self.glyph.contains('\u{200D}') {
+            return newest;
+        }
+        // Families and couples were the first joined sequences, in 2.0, the rest started
+        // with the gendered people and jobs of 4.0.
+        let people = |c: char| {
+            matches!(
+                c,
+                '\u{1F466}'
+                    ..='\u{1F469}' | '\u{2764}' | '\u{1F48B}' | '\u{200D}' | '\u{FE0F}'
+            )
+        };
+        let first = if self.glyph.chars().all(people) { 2.0 } else { 4.0 };
+        newest.max(first)
+    }
+}
+
+const fn emoji(name: &'static str, glyph: &'static str, group: Category) -> Emoji {
+    Emoji { name, glyph, group, keywords: keywords(name) }
+}
+
+pub static EMOJIS: &[Emoji] = &[
+    emoji("grinning", "😀", Smileys),
+    emoji("grimacing", "😬", Smileys),
+    emoji("grin", "😁", Smileys),
+    emoji("joy", "😂", Smileys),
+    emoji("rofl", "🤣", Smileys),
+    emoji("partying", "🥳", Smileys),
+    emoji("smiley", "😃", Smileys),
+    emoji("smile", "😄", Smileys),
+    emoji("sweat_smile", "😅", Smileys),
+    emoji("laughing", "😆", Smileys),
+    emoji("innocent", "😇", Smileys),
+    emoji("wink", "😉", Smileys),
+    emoji("blush", "😊", Smileys),
+    emoji("slightly_smiling_face", "🙂", Smileys),
+    emoji("upside_down_face", "🙃", Smileys),
+    emoji("relaxed", "☺️", Smileys),
+    emoji("yum", "😋", Smileys),
+    emoji("relieved", "😌", Smileys),
+    emoji("heart_eyes", "😍", Smileys),
+    emoji("smiling_face_with_three_hearts", "🥰", Smileys),
+    emoji("kissing_heart", "😘", Smileys),
+    emoji("kissing", "😗", Smileys),
+    emoji("kissing_smiling_eyes", "😙", Smileys),
+    emoji("kissing_closed_eyes", "😚", Smileys),
+    emoji("stuck_out_tongue_winking_eye", "😜", Smileys),
+    emoji("zany", "🤪", Smileys),
+    emoji("raised_eyebrow", "🤨", Smileys),
+    emoji("monocle", "🧐", Smileys),
+    emoji("stuck_out_tongue_closed_eyes", "😝", Smileys),
+    emoji("stuck_out_tongue", "😛", Smileys),
+    emoji("money_mouth_face", "🤑", Smileys),
+    emoji("nerd_face", "🤓", Smileys),
+    emoji("sunglasses", "😎", Smileys),
+    emoji("star_struck", "🤩", Smileys),
+    emoji("clown_face", "🤡", Smileys),
+    emoji("cowboy_hat_face", "🤠", Smileys),
+    emoji("hugs", "🤗", Smileys),
+    emoji("smirk", "😏", Smileys),
+    emoji("no_mouth", "😶", Smileys),
+    emoji("neutral_face", "😐", Smileys),
+    emoji("expressionless", "😑", Smileys),
+    emoji("unamused", "😒", Smileys),
+    emoji("roll_eyes", "🙄", Smileys),
+    emoji("thinking", "🤔", Smileys),
+    emoji("lying_face", "🤥", Smileys),
+    emoji("hand_over_mouth", "🤭", Smileys),
+    emoji("shushing", "🤫", Smileys),
+    emoji("symbols_over_mouth", "🤬", Smileys),
+    emoji("exploding_head", "🤯", Smileys),
+    emoji("flushed", "😳", Smileys),
+    emoji("disappointed", "😞", Smileys),
+    emoji("worried", "😟", Smileys),
+    emoji("angry", "😠", Smileys),
+    emoji("rage", "😡", Smileys),
+    emoji("pensive", "😔", Smileys),
+    emoji("confused", "😕", Smileys),
+    emoji("slightly_frowning_face", "🙁", Smileys),
+    emoji("frowning_face", "☹️", Smileys),
+    emoji("persevere", "😣", Smileys),
+    emoji("confounded", "😖", Smileys),
+    emoji("tired_face", "😫", Smileys),
+    emoji("weary", "😩", Smileys),
+    emoji("pleading", "🥺", Smileys),
+    emoji("triumph", "😤", Smileys),
+    emoji("open_mouth", "😮", Smileys),
+    emoji("scream", "😱", Smileys),
+    emoji("fearful", "😨", Smileys),
+    emoji("cold_sweat", "😰", Smileys),
+    emoji("hushed", "😯", Smileys),
+    emoji("frowning", "😦", Smileys),
+    emoji("anguished", "😧", Smileys),
+    emoji("cry", "😢", Smileys),
+    emoji("disappointed_relieved", "😥", Smileys),
+    emoji("drooling_face", "🤤", Smileys),
+    emoji("sleepy", "😪", Smileys),
+    emoji("sweat", "😓", Smileys),
+    emoji("hot", "🥵", Smileys),
+    emoji("cold", "🥶", Smileys),
+    emoji("sob", "😭", Smileys),
+    emoji("dizzy_face", "😵", Smileys),
+    emoji("astonished", "😲", Smileys),
+    emoji("zipper_mouth_face", "🤐", Smileys),
+    emoji("nauseated_face", "🤢", Smileys),
+    emoji("sneezing_face", "🤧", Smileys),
+    emoji("vomiting", "🤮", Smileys),
+    emoji("mask", "😷", Smileys),
+    emoji("face_with_thermometer", "🤒", Smileys),
+    emoji("face_with_head_bandage", "🤕", Smileys),
+    emoji("woozy", "🥴", Smileys),
+    emoji("sleeping", "😴", Smileys),
+    emoji("zzz", "💤", Smileys),
+    emoji("poop", "💩", Smileys),
+    emoji("smiling_imp", "😈", Smileys),
+    emoji("imp", "👿", Smileys),
+    emoji("japanese_ogre", "👹", Smileys),
+    emoji("japanese_goblin", "👺", Smileys),
+    emoji("skull", "💀", Smileys),
+    emoji("ghost", "👻", Smileys),
+    emoji("alien", "👽", Smileys),
+    emoji("robot", "🤖", Smileys),
+    emoji("smiley_cat", "😺", Smileys),
+    emoji("smile_cat", "😸", Smileys),
+    emoji("joy_cat", "😹", Smileys),
+    emoji("heart_eyes_cat", "😻", Smileys),
+    emoji("smirk_cat", "😼", Smileys),
+    emoji("kissing_cat", "😽", Smileys),
+    emoji("scream_cat", "🙀", Smileys),
+    emoji("crying_cat_face", "😿", Smileys),
+    emoji("pouting_cat", "😾", Smileys),
+    emoji("palms_up", "🤲", Smileys),
+    emoji("raised_hands", "🙌", Smileys),
+    emoji("clap", "👏", Smileys),
+    emoji("wave", "👋", Smileys),
+    emoji("call_me_hand", "🤙", Smileys),
+    emoji("+1", "👍", Smileys),
+    emoji("-1", "👎", Smileys),
+    emoji("facepunch", "👊", Smileys),
+    emoji("fist", "✊", Smileys),
+    emoji("fist_left", "🤛", Smileys),
+    emoji("fist_right", "🤜", Smileys),
+    emoji("v", "✌️", Smileys),
+    emoji("ok_hand", "👌", Smileys),
+    emoji("raised_hand", "✋", Smileys),
+    emoji("raised_back_of_hand", "🤚", Smileys),
+    emoji("open_hands", "👐", Smileys),
+    emoji("muscle", "💪", Smileys),
+    emoji("pray", "🙏", Smileys),
+    emoji("foot", "🦶", Smileys),
+    emoji("leg", "🦵", Smileys),
+    emoji("handshake", "🤝", Smileys),
+    emoji("point_up", "☝️", Smileys),
+    emoji("point_up_2", "👆", Smileys),
+    emoji("point_down", "👇", Smileys),
+    emoji("point_left", "👈", Smileys),
+    emoji("point_right", "👉", Smileys),
+    emoji("fu", "🖕", Smileys),
+    emoji("raised_hand_with_fingers_splayed", "🖐️", Smileys),
+    emoji("love_you", "🤟", Smileys),
+    emoji("metal", "🤘", Smileys),
+    emoji("crossed_fingers", "🤞", Smileys),
+    emoji("vulcan_salute", "🖖", Smileys),
+    emoji("writing_hand", "✍️", Smileys),
+    emoji("selfie", "🤳", Smileys),
+    emoji("nail_care", "💅", Smileys),
+    emoji("lips", "👄", Smileys),
+    emoji("tooth", "🦷", Smileys),
+    emoji("tongue", "👅", Smileys),
+    emoji("ear", "👂", Smileys),
+    emoji("nose", "👃", Smileys),
+    emoji("eye", "👁️", Smileys),
+    emoji("eyes", "👀", Smileys),
+    emoji("brain", "🧠", Smileys),
+    emoji("bust_in_silhouette", "👤", Smileys),
+    emoji("busts_in_silhouette", "👥", Smileys),
+    emoji("speaking_head", "🗣️", Smileys),
+    emoji("baby", "👶", Smileys),
+    emoji("child", "🧒", Smileys),
+    emoji("boy", "👦", Smileys),
+    emoji("girl", "👧", Smileys),
+    emoji("adult", "🧑", Smileys),
+    emoji("man", "👨", Smileys),
+    emoji("woman", "👩", Smileys),
+    emoji("blonde_woman", "👱‍♀️", Smileys),
+    emoji("blonde_man", "👱", Smileys),
+    emoji("bearded_person", "🧔", Smileys),
+    emoji("older_adult", "🧓", Smileys),
+    emoji("older_man", "👴", Smileys),
+    emoji("older_woman", "👵", Smileys),
+    emoji("man_with_gua_pi_mao", "👲", Smileys),
+    emoji("woman_with_headscarf", "🧕", Smileys),
+    emoji("woman_with_turban", "👳‍♀️", Smileys),
+    emoji("man_with_turban", "👳", Smileys),
+    emoji("policewoman", "👮‍♀️", Smileys),
+    emoji("policeman", "👮", Smileys),
+    emoji("construction_worker_woman", "👷‍♀️", Smileys),
+    emoji("construction_worker_man", "👷", Smileys),
+    emoji("guardswoman", "💂‍♀️", Smileys),
+    emoji("guardsman", "💂", Smileys),
+    emoji("female_detective", "🕵️‍♀️", Smileys),
+    emoji("male_detective", "🕵️", Smileys),
+    emoji("woman_health_worker", "👩‍⚕️", Smileys),
+    emoji("man_health_worker", "👨‍⚕️", Smileys),
+    emoji("woman_farmer", "👩‍🌾", Smileys),
+    emoji("man_farmer", "👨‍🌾", Smileys),
+    emoji("woman_cook", "👩‍🍳", Smileys),
+    emoji("man_cook", "👨‍🍳", Smileys),
+    emoji("woman_student", "👩‍🎓", Smileys),
+    emoji("man_student", "👨‍🎓", Smileys),
+    emoji("woman_singer", "👩‍🎤", Smileys),
+    emoji("man_singer", "👨‍🎤", Smileys),
+    emoji("woman_teacher", "👩‍🏫", Smileys),
+    emoji("man_teacher", "👨‍🏫", Smileys),
+    emoji("woman_factory_worker", "👩‍🏭", Smileys),
+    emoji("man_factory_worker", "👨‍🏭", Smileys),
+    emoji("woman_technologist", "👩‍💻", Smileys),
+    emoji("man_technologist", "👨‍💻", Smileys),
+    emoji("woman_office_worker", "👩‍💼", Smileys),
+    emoji("man_office_worker", "👨‍💼", Smileys),
+    emoji("woman_mechanic", "👩‍🔧", Smileys),
+    emoji("man_mechanic", "👨‍🔧", Smileys),
+    emoji("woman_scientist", "👩‍🔬", Smileys),
+    emoji("man_scientist", "👨‍🔬", Smileys),
+    emoji("woman_artist", "👩‍🎨", Smileys),
+    emoji("man_artist", "👨‍🎨", Smileys),
+    emoji("woman_firefighter", "👩‍🚒", Smileys),
+    emoji("man_firefighter", "👨‍🚒", Smileys),
+    emoji("woman_pilot", "👩‍✈️", Smileys),
+    emoji("man_pilot", "👨‍✈️", Smileys),
+    emoji("woman_astronaut", "👩‍🚀", Smileys),
+    emoji("man_astronaut", "👨‍🚀", Smileys),
+    emoji("woman_judge", "👩‍⚖️", Smileys),
+    emoji("man_judge", "👨‍⚖️", Smileys),
+    emoji("woman_superhero", "🦸‍♀️", Smileys),
+    emoji("man_superhero", "🦸‍♂️", Smileys),
+    emoji("woman_supervillain", "🦹‍♀️", Smileys),
+    emoji("man_supervillain", "🦹‍♂️", Smileys),
+    emoji("mrs_claus", "🤶", Smileys),
+    emoji("santa", "🎅", Smileys),
+    emoji("sorceress", "🧙‍♀️", Smileys),
+    emoji("wizard", "🧙‍♂️", Smileys),
+    emoji("woman_elf", "🧝‍♀️", Smileys),
+    emoji("man_elf", "🧝‍♂️", Smileys),
+    emoji("woman_vampire", "🧛‍♀️", Smileys),
+    emoji("man_vampire", "🧛‍♂️", Smileys),
+    emoji("woman_zombie", "🧟‍♀️", Smileys),
+    emoji("man_zombie", "🧟‍♂️", Smileys),
+    emoji("woman_genie", "🧞‍♀️", Smileys),
+    emoji("man_genie", "🧞‍♂️", Smileys),
+    emoji("mermaid", "🧜‍♀️", Smileys),
+    emoji("merman", "🧜‍♂️", Smileys),
+    emoji("woman_fairy", "🧚‍♀️", Smileys),
+    emoji("man_fairy", "🧚‍♂️", Smileys),
+    emoji("angel", "👼", Smileys),
+    emoji("pregnant_woman", "🤰", Smileys),
+    emoji("breastfeeding", "🤱", Smileys),
+    emoji("princess", "👸", Smileys),
+    emoji("prince", "🤴", Smileys),
+    emoji("bride_with_veil", "👰", Smileys),
+    emoji("man_in_tuxedo", "🤵", Smileys),
+    emoji("running_woman", "🏃‍♀️", Smileys),
+    emoji("running_man", "🏃", Smileys),
+    emoji("walking_woman", "🚶‍♀️", Smileys),
+    emoji("walking_man", "🚶", Smileys),
+    emoji("dancer", "💃", Smileys),
+    emoji("man_dancing", "🕺", Smileys),
+    emoji("dancing_women", "👯", Smileys),
+    emoji("dancing_men", "👯‍♂️", Smileys),
+    emoji("couple", "👫", Smileys),
+    emoji("two_men_holding_hands", "👬", Smileys),
+    emoji("two_women_holding_hands", "👭", Smileys),
+    emoji("bowing_woman", "🙇‍♀️", Smileys),
+    emoji("bowing_man", "🙇", Smileys),
+    emoji("man_facepalming", "🤦‍♂️", Smileys),
+    emoji("woman_facepalming", "🤦‍♀️", Smileys),
+    emoji("woman_shrugging", "🤷", Smileys),
+    emoji("man_shrugging", "🤷‍♂️", Smileys),
+    emoji("tipping_hand_woman", "💁", Smileys),
+    emoji("tipping_hand_man", "💁‍♂️", Smileys),
+    emoji("no_good_woman", "🙅", Smileys),
+    emoji("no_good_man", "🙅‍♂️", Smileys),
+    emoji("ok_woman", "🙆", Smileys),
+    emoji("ok_man", "🙆‍♂️", Smileys),
+    emoji("raising_hand_woman", "🙋", Smileys),
+    emoji("raising_hand_man", "🙋‍♂️", Smileys),
+    emoji("pouting_woman", "🙎", Smileys),
+    emoji("pouting_man", "🙎‍♂️", Smileys),
+    emoji("frowning_woman", "🙍", Smileys),
+    emoji("frowning_man", "🙍‍♂️", Smileys),
+    emoji("haircut_woman", "💇", Smileys),
+    emoji("haircut_man", "💇‍♂️", Smileys),
+    emoji("massage_woman", "💆", Smileys),
+    emoji("massage_man", "💆‍♂️", Smileys),
+    emoji("woman_in_steamy_room", "🧖‍♀️", Smileys),
+    emoji("man_in_steamy_room", "🧖‍♂️", Smileys),
+    emoji("couple_with_heart_woman_man", "💑", Smileys),
+    emoji("couple_with_heart_woman_woman", "👩‍❤️‍👩", Smileys),
+    emoji("couple_with_heart_man_man", "👨‍❤️‍👨", Smileys),
+    emoji("couplekiss_man_woman", "💏", Smileys),
+    emoji("couplekiss_woman_woman", "👩‍❤️‍💋‍👩", Smileys),
+    emoji("couplekiss_man_man", "👨‍❤️‍💋‍👨", Smileys),
+    emoji("family_man_woman_boy", "👪", Smileys),
+    emoji("family_man_woman_girl", "👨‍👩‍👧", Smileys),
+    emoji("family_man_woman_girl_boy", "👨‍👩‍👧‍👦", Smileys),
+    emoji("family_man_woman_boy_boy", "👨‍👩‍👦‍👦", Smileys),
+    emoji("family_man_woman_girl_girl", "👨‍👩‍👧‍👧", Smileys),
+    emoji("family_woman_woman_boy", "👩‍👩‍👦", Smileys),
+    emoji("family_woman_woman_girl", "👩‍👩‍👧", Smileys),
+    emoji("family_woman_woman_girl_boy", "👩‍👩‍👧‍👦", Smileys),
+    emoji("family_woman_woman_boy_boy", "👩‍👩‍👦‍👦", Smileys),
+    emoji("family_woman_woman_girl_girl", "👩‍👩‍👧‍👧", Smileys),
+    emoji("family_man_man_boy", "👨‍👨‍👦", Smileys),
+    emoji("family_man_man_girl", "👨‍👨‍👧", Smileys),
+    emoji("family_man_man_girl_boy", "👨‍👨‍👧‍👦", Smileys),
+    emoji("family_man_man_boy_boy", "👨‍👨‍👦‍👦", Smileys),
+    emoji("family_man_man_girl_girl", "👨‍👨‍👧‍👧", Smileys),
+    emoji("family_woman_boy", "👩‍👦", Smileys),
+    emoji("family_woman_girl", "👩‍👧", Smileys),
+    emoji("family_woman_girl_boy", "👩‍👧‍👦", Smileys),
+    emoji("family_woman_boy_boy", "👩‍👦‍👦", Smileys),
+    emoji("family_woman_girl_girl", "👩‍👧‍👧", Smileys),
+    emoji("family_man_boy", "👨‍👦", Smileys),
+    emoji("family_man_girl", "👨‍👧", Smileys),
+    emoji("family_man_girl_boy", "👨‍👧‍👦", Smileys),
+    emoji("family_man_boy_boy", "👨‍👦‍👦", Smileys),
+    emoji("family_man_girl_girl", "👨‍👧‍👧", Smileys),
+    emoji("yarn", "🧶", Smileys),
+    emoji("thread", "🧵", Smileys),
+    emoji("coat", "🧥", Smileys),
+    emoji("labcoat", "🥼", Smileys),
+    emoji("womans_clothes", "👚", Smileys),
+    emoji("tshirt", "👕", Smileys),
+    emoji("jeans", "👖", Smileys),
+    emoji("necktie", "👔", Smileys),
+    emoji("dress", "👗", Smileys),
+    emoji("bikini", "👙", Smileys),
+    emoji("kimono", "👘", Smileys),
+    emoji("lipstick", "💄", Smileys),
+    emoji("kiss", "💋", Smileys),
+    emoji("footprints", "👣", Smileys),
+    emoji("flat_shoe", "🥿", Smileys),
+    emoji("high_heel", "👠", Smileys),
+    emoji("sandal", "👡", Smileys),
+    emoji("boot", "👢", Smileys),
+    emoji("mans_shoe", "👞", Smileys),
+    emoji("athletic_shoe", "👟", Smileys),
+    emoji("hiking_boot", "🥾", Smileys),
+    emoji("socks", "🧦", Smileys),
+    emoji("gloves", "🧤", Smileys),
+    emoji("scarf", "🧣", Smileys),
+    emoji("womans_hat", "👒", Smileys),
+    emoji("tophat", "🎩", Smileys),
+    emoji("billed_hat", "🧢", Smileys),
+    emoji("rescue_worker_helmet", "⛑️", Smileys),
+    emoji("mortar_board", "🎓", Smileys),
+    emoji("crown", "👑", Smileys),
+    emoji("school_satchel", "🎒", Smileys),
+    emoji("luggage", "🧳", Smileys),
+    emoji("pouch", "👝", Smileys),
+    emoji("purse", "👛", Smileys),
+    emoji("handbag", "👜", Smileys),
+    emoji("briefcase", "💼", Smileys),
+    emoji("eyeglasses", "👓", Smileys),
+    emoji("dark_sunglasses", "🕶️", Smileys),
+    emoji("goggles", "🥽", Smileys),
+    emoji("ring", "💍", Smileys),
+    emoji("closed_umbrella", "🌂", Smileys),
+    emoji("dog", "🐶", Animals),
+    emoji("cat", "🐱", Animals),
+    emoji("mouse", "🐭", Animals),
+    emoji("hamster", "🐹", Animals),
+    emoji("rabbit", "🐰", Animals),
+    emoji("fox_face", "🦊", Animals),
+    emoji("bear", "🐻", Animals),
+    emoji("panda_face", "🐼", Animals),
+    emoji("koala", "🐨", Animals),
+    emoji("tiger", "🐯", Animals),
+    emoji("lion", "🦁", Animals),
+    emoji("cow", "🐮", Animals),
+    emoji("pig", "🐷", Animals),
+    emoji("pig_nose", "🐽", Animals),
+    emoji("frog", "🐸", Animals),
+    emoji("squid", "🦑", Animals),
+    emoji("octopus", "🐙", Animals),
+    emoji("shrimp", "🦐", Animals),
+    emoji("monkey_face", "🐵", Animals),
+    emoji("gorilla", "🦍", Animals),
+    emoji("see_no_evil", "🙈", Animals),
+    emoji("hear_no_evil", "🙉", Animals),
+    emoji("speak_no_evil", "🙊", Animals),
+    emoji("monkey", "🐒", Animals),
+    emoji("chicken", "🐔", Animals),
+    emoji("penguin", "🐧", Animals),
+    emoji("bird", "🐦", Animals),
+    emoji("baby_chick", "🐤", Animals),
+    emoji("hatching_chick", "🐣", Animals),
+    emoji("hatched_chick", "🐥", Animals),
+    emoji("duck", "🦆", Animals),
+    emoji("eagle", "🦅", Animals),
+    emoji("owl", "🦉", Animals),
+    emoji("bat", "🦇", Animals),
+    emoji("wolf", "🐺", Animals),
+    emoji("boar", "🐗", Animals),
+    emoji("horse", "🐴", Animals),
+    emoji("unicorn", "🦄", Animals),
+    emoji("honeybee", "🐝", Animals),
+    emoji("bug", "🐛", Animals),
+    emoji("butterfly", "🦋", Animals),
+    emoji("snail", "🐌", Animals),
+    emoji("beetle", "🐞", Animals),
+    emoji("ant", "🐜", Animals),
+    emoji("grasshopper", "🦗", Animals),
+    emoji("spider", "🕷️", Animals),
+    emoji("scorpion", "🦂", Animals),
+    emoji("crab", "🦀", Animals),
+    emoji("snake", "🐍", Animals),
+    emoji("lizard", "🦎", Animals),
+    emoji("t-rex", "🦖", Animals),
+    emoji("sauropod", "🦕", Animals),
+    emoji("turtle", "🐢", Animals),
+    emoji("tropical_fish", "🐠", Animals),
+    emoji("fish", "🐟", Animals),
+    emoji("blowfish", "🐡", Animals),
+    emoji("dolphin", "🐬", Animals),
+    emoji("shark", "🦈", Animals),
+    emoji("whale", "🐳", Animals),
+    emoji("whale2", "🐋", Animals),
+    emoji("crocodile", "🐊", Animals),
+    emoji("leopard", "🐆", Animals),
+    emoji("zebra", "🦓", Animals),
+    emoji("tiger2", "🐅", Animals),
+    emoji("water_buffalo", "🐃", Animals),
+    emoji("ox", "🐂", Animals),
+    emoji("cow2", "🐄", Animals),
+    emoji("deer", "🦌", Animals),
+    emoji("dromedary_camel", "🐪", Animals),
+    emoji("camel", "🐫", Animals),
+    emoji("giraffe", "🦒", Animals),
+    emoji("elephant", "🐘", Animals),
+    emoji("rhinoceros", "🦏", Animals),
+    emoji("goat", "🐐", Animals),
+    emoji("ram", "🐏", Animals),
+    emoji("sheep", "🐑", Animals),
+    emoji("racehorse", "🐎", Animals),
+    emoji("pig2", "🐖", Animals),
+    emoji("rat", "🐀", Animals),
+    emoji("mouse2", "🐁", Animals),
+    emoji("rooster", "🐓", Animals),
+    emoji("turkey", "🦃", Animals),
+    emoji("dove", "🕊️", Animals),
+    emoji("dog2", "🐕", Animals),
+    emoji("poodle", "🐩", Animals),
+    emoji("cat2", "🐈", Animals),
+    emoji("rabbit2", "🐇", Animals),
+    emoji("chipmunk", "🐿️", Animals),
+    emoji("hedgehog", "🦔", Animals),
+    emoji("raccoon", "🦝", Animals),
+    emoji("llama", "🦙", Animals),
+    emoji("hippopotamus", "🦛", Animals),
+    emoji("kangaroo", "🦘", Animals),
+    emoji("badger", "🦡", Animals),
+    emoji("swan", "🦢", Animals),
+    emoji("peacock", "🦚", Animals),
+    emoji("parrot", "🦜", Animals),
+    emoji("lobster", "🦞", Animals),
+    emoji("mosquito", "🦟", Animals),
+    emoji("paw_prints", "🐾", Animals),
+    emoji("dragon", "🐉", Animals),
+    emoji("dragon_face", "🐲", Animals),
+    emoji("cactus", "🌵", Animals),
+    emoji("christmas_tree", "🎄", Animals),
+    emoji("evergreen_tree", "🌲", Animals),
+    emoji("deciduous_tree", "🌳", Animals),
+    emoji("palm_tree", "🌴", Animals),
+    emoji("seedling", "🌱", Animals),
+    emoji("herb", "🌿", Animals),
+    emoji("shamrock", "☘️", Animals),
+    emoji("four_leaf_clover", "🍀", Animals),
+    emoji("bamboo", "🎍", Animals),
+    emoji("tanabata_tree", "🎋", Animals),
+    emoji("leaves", "🍃", Animals),
+    emoji("fallen_leaf", "🍂", Animals),
+    emoji("maple_leaf", "🍁", Animals),
+    emoji("ear_of_rice", "🌾", Animals),
+    emoji("hibiscus", "🌺", Animals),
+    emoji("sunflower", "🌻", Animals),
+    emoji("rose", "🌹", Animals),
+    emoji("wilted_flower", "🥀", Animals),
+    emoji("tulip", "🌷", Animals),
+    emoji("blossom", "🌼", Animals),
+    emoji("cherry_blossom", "🌸", Animals),
+    emoji("bouquet", "💐", Animals),
+    emoji("mushroom", "🍄", Animals),
+    emoji("chestnut", "🌰", Animals),
+    emoji("jack_o_lantern", "🎃", Animals),
+    emoji("shell", "🐚", Animals),
+    emoji("spider_web", "🕸️", Animals),
+    emoji("earth_americas", "🌎", Animals),
+    emoji("earth_africa", "🌍", Animals),
+    emoji("earth_asia", "🌏", Animals),
+    emoji("full_moon", "🌕", Animals),
+    emoji("waning_gibbous_moon", "🌖", Animals),
+    emoji("last_quarter_moon", "🌗", Animals),
+    emoji("waning_crescent_moon", "🌘", Animals),
+    emoji("new_moon", "🌑", Animals),
+    emoji("waxing_crescent_moon", "🌒", Animals),
+    emoji("first_quarter_moon", "🌓", Animals),
+    emoji("waxing_gibbous_moon", "🌔", Animals),
+    emoji("new_moon_with_face", "🌚", Animals),
+    emoji("full_moon_with_face", "🌝", Animals),
+    emoji("first_quarter_moon_with_face", "🌛", Animals),
+    emoji("last_quarter_moon_with_face", "🌜", Animals),
+    emoji("sun_with_face", "🌞", Animals),
+    emoji("crescent_moon", "🌙", Animals),
+    emoji("star", "⭐", Animals),
+    emoji("star2", "🌟", Animals),
+    emoji("dizzy", "💫", Animals),
+    emoji("sparkles", "✨", Animals),
+    emoji("comet", "☄️", Animals),
+    emoji("sunny", "☀️", Animals),
+    emoji("sun_behind_small_cloud", "🌤️", Animals),
+    emoji("partly_sunny", "⛅", Animals),
+    emoji("sun_behind_large_cloud", "🌥️", Animals),
+    emoji("sun_behind_rain_cloud", "🌦️", Animals),
+    emoji("cloud", "☁️", Animals),
+    emoji("cloud_with_rain", "🌧️", Animals),
+    emoji("cloud_with_lightning_and_rain", "⛈️", Animals),
+    emoji("cloud_with_lightning", "🌩️", Animals),
+    emoji("zap", "⚡", Animals),
+    emoji("fire", "🔥", Animals),
+    emoji("boom", "💥", Animals),
+    emoji("snowflake", "❄️", Animals),
+    emoji("cloud_with_snow", "🌨️", Animals),
+    emoji("snowman", "⛄", Animals),
+    emoji("snowman_with_snow", "☃️", Animals),
+    emoji("wind_face", "🌬️", Animals),
+    emoji("dash", "💨", Animals),
+    emoji("tornado", "🌪️", Animals),
+    emoji("fog", "🌫️", Animals),
+    emoji("open_umbrella", "☂️", Animals),
+    emoji("umbrella", "☔", Animals),
+    emoji("droplet", "💧", Animals),
+    emoji("sweat_drops", "💦", Animals),
+    emoji("ocean", "🌊", Animals),
+    emoji("green_apple", "🍏", Food),
+    emoji("apple", "🍎", Food),
+    emoji("pear", "🍐", Food),
+    emoji("tangerine", "🍊", Food),
+    emoji("lemon", "🍋", Food),
+    emoji("banana", "🍌", Food),
+    emoji("watermelon", "🍉", Food),
+    emoji("grapes", "🍇", Food),
+    emoji("strawberry", "🍓", Food),
+    emoji("melon", "🍈", Food),
+    emoji("cherries", "🍒", Food),
+    emoji("peach", "🍑", Food),
+    emoji("pineapple", "🍍", Food),
+    emoji("coconut", "🥥", Food),
+    emoji("kiwi_fruit", "🥝", Food),
+    emoji("mango", "🥭", Food),
+    emoji("avocado", "🥑", Food),
+    emoji("broccoli", "🥦", Food),
+    emoji("tomato", "🍅", Food),
+    emoji("eggplant", "🍆", Food),
+    emoji("cucumber", "🥒", Food),
+    emoji("carrot", "🥕", Food),
+    emoji("hot_pepper", "🌶️", Food),
+    emoji("potato", "🥔", Food),
+    emoji("corn", "🌽", Food),
+    emoji("leafy_greens", "🥬", Food),
+    emoji("sweet_potato", "🍠", Food),
+    emoji("peanuts", "🥜", Food),
+    emoji("honey_pot", "🍯", Food),
+    emoji("croissant", "🥐", Food),
+    emoji("bread", "🍞", Food),
+    emoji("baguette_bread", "🥖", Food),
+    emoji("bagel", "🥯", Food),
+    emoji("pretzel", "🥨", Food),
+    emoji("cheese", "🧀", Food),
+    emoji("egg", "🥚", Food),
+    emoji("bacon", "🥓", Food),
+    emoji("steak", "🥩", Food),
+    emoji("pancakes", "🥞", Food),
+    emoji("poultry_leg", "🍗", Food),
+    emoji("meat_on_bone", "🍖", Food),
+    emoji("bone", "🦴", Food),
+    emoji("fried_shrimp", "🍤", Food),
+    emoji("fried_egg", "🍳", Food),
+    emoji("hamburger", "🍔", Food),
+    emoji("fries", "🍟", Food),
+    emoji("stuffed_flatbread", "🥙", Food),
+    emoji("hotdog", "🌭", Food),
+    emoji("pizza", "🍕", Food),
+    emoji("sandwich", "🥪", Food),
+    emoji("canned_food", "🥫", Food),
+    emoji("spaghetti", "🍝", Food),
+    emoji("taco", "🌮", Food),
+    emoji("burrito", "🌯", Food),
+    emoji("green_salad", "🥗", Food),
+    emoji("shallow_pan_of_food", "🥘", Food),
+    emoji("ramen", "🍜", Food),
+    emoji("stew", "🍲", Food),
+    emoji("fish_cake", "🍥", Food),
+    emoji("fortune_cookie", "🥠", Food),
+    emoji("sushi", "🍣", Food),
+    emoji("bento", "🍱", Food),
+    emoji("curry", "🍛", Food),
+    emoji("rice_ball", "🍙", Food),
+    emoji("rice", "🍚", Food),
+    emoji("rice_cracker", "🍘", Food),
+    emoji("oden", "🍢", Food),
+    emoji("dango", "🍡", Food),
+    emoji("shaved_ice", "🍧", Food),
+    emoji("ice_cream", "🍨", Food),
+    emoji("icecream", "🍦", Food),
+    emoji("pie", "🥧", Food),
+    emoji("cake", "🍰", Food),
+    emoji("cupcake", "🧁", Food),
+    emoji("moon_cake", "🥮", Food),
+    emoji("birthday", "🎂", Food),
+    emoji("custard", "🍮", Food),
+    emoji("candy", "🍬", Food),
+    emoji("lollipop", "🍭", Food),
+    emoji("chocolate_bar", "🍫", Food),
+    emoji("popcorn", "🍿", Food),
+    emoji("dumpling", "🥟", Food),
+    emoji("doughnut", "🍩", Food),
+    emoji("cookie", "🍪", Food),
+    emoji("milk_glass", "🥛", Food),
+    emoji("beer", "🍺", Food),
+    emoji("beers", "🍻", Food),
+    emoji("clinking_glasses", "🥂", Food),
+    emoji("wine_glass", "🍷", Food),
+    emoji("tumbler_glass", "🥃", Food),
+    emoji("cocktail", "🍸", Food),
+    emoji("tropical_drink", "🍹", Food),
+    emoji("champagne", "🍾", Food),
+    emoji("sake", "🍶", Food),
+    emoji("tea", "🍵", Food),
+    emoji("cup_with_straw", "🥤", Food),
+    emoji("coffee", "☕", Food),
+    emoji("baby_bottle", "🍼", Food),
+    emoji("salt", "🧂", Food),
+    emoji("spoon", "🥄", Food),
+    emoji("fork_and_knife", "🍴", Food),
+    emoji("plate_with_cutlery", "🍽️", Food),
+    emoji("bowl_with_spoon", "🥣", Food),
+    emoji("takeout_box", "🥡", Food),
+    emoji("chopsticks", "🥢", Food),
+    emoji("soccer", "⚽", Activity),
+    emoji("basketball", "🏀", Activity),
+    emoji("football", "🏈", Activity),
+    emoji("baseball", "⚾", Activity),
+    emoji("softball", "🥎", Activity),
+    emoji("tennis", "🎾", Activity),
+    emoji("volleyball", "🏐", Activity),
+    emoji("rugby_football", "🏉", Activity),
+    emoji("flying_disc", "🥏", Activity),
+    emoji("8ball", "🎱", Activity),
+    emoji("golf", "⛳", Activity),
+    emoji("golfing_woman", "🏌️‍♀️", Activity),
+    emoji("golfing_man", "🏌️", Activity),
+    emoji("ping_pong", "🏓", Activity),
+    emoji("badminton", "🏸", Activity),
+    emoji("goal_net", "🥅", Activity),
+    emoji("ice_hockey", "🏒", Activity),
+    emoji("field_hockey", "🏑", Activity),
+    emoji("lacrosse", "🥍", Activity),
+    emoji("cricket", "🏏", Activity),
+    emoji("ski", "🎿", Activity),
+    emoji("skier", "⛷️", Activity),
+    emoji("snowboarder", "🏂", Activity),
+    emoji("person_fencing", "🤺", Activity),
+    emoji("women_wrestling", "🤼‍♀️", Activity),
+    emoji("men_wrestling", "🤼‍♂️", Activity),
+    emoji("woman_cartwheeling", "🤸‍♀️", Activity),
+    emoji("man_cartwheeling", "🤸‍♂️", Activity),
+    emoji("woman_playing_handball", "🤾‍♀️", Activity),
+    emoji("man_playing_handball", "🤾‍♂️", Activity),
+    emoji("ice_skate", "⛸️", Activity),
+    emoji("curling_stone", "🥌", Activity),
+    emoji("skateboard", "🛹", Activity),
+    emoji("sled", "🛷", Activity),
+    emoji("bow_and_arrow", "🏹", Activity),
+    emoji("fishing_pole_and_fish", "🎣", Activity),
+    emoji("boxing_glove", "🥊", Activity),
+    emoji("martial_arts_uniform", "🥋", Activity),
+    emoji("rowing_woman", "🚣‍♀️", Activity),
+    emoji("rowing_man", "🚣", Activity),
+    emoji("climbing_woman", "🧗‍♀️", Activity),
+    emoji("climbing_man", "🧗‍♂️", Activity),
+    emoji("swimming_woman", "🏊‍♀️", Activity),
+    emoji("swimming_man", "🏊", Activity),
+    emoji("woman_playing_water_polo", "🤽‍♀️", Activity),
+    emoji("man_playing_water_polo", "🤽‍♂️", Activity),
+    emoji("woman_in_lotus_position", "🧘‍♀️", Activity),
+    emoji("man_in_lotus_position", "🧘‍♂️", Activity),
+    emoji("surfing_woman", "🏄‍♀️", Activity),
+    emoji("surfing_man", "🏄", Activity),
+    emoji("bath", "🛀", Activity),
+    emoji("basketball_woman", "⛹️‍♀️", Activity),
+    emoji("basketball_man", "⛹️", Activity),
+    emoji("weight_lifting_woman", "🏋️‍♀️", Activity),
+    emoji("weight_lifting_man", "🏋️", Activity),
+    emoji("biking_woman", "🚴‍♀️", Activity),
+    emoji("biking_man", "🚴", Activity),
+    emoji("mountain_biking_woman", "🚵‍♀️", Activity),
+    emoji("mountain_biking_man", "🚵", Activity),
+    emoji("horse_racing", "🏇", Activity),
+    emoji("business_suit_levitating", "🕴️", Activity),
+    emoji("trophy", "🏆", Activity),
+    emoji("running_shirt_with_sash", "🎽", Activity),
+    emoji("medal_sports", "🏅", Activity),
+    emoji("medal_military", "🎖️", Activity),
+    emoji("1st_place_medal", "🥇", Activity),
+    emoji("2nd_place_medal", "🥈", Activity),
+    emoji("3rd_place_medal", "🥉", Activity),
+    emoji("reminder_ribbon", "🎗️", Activity),
+    emoji("rosette", "🏵️", Activity),
+    emoji("ticket", "🎫", Activity),
+    emoji("tickets", "🎟️", Activity),
+    emoji("performing_arts", "🎭", Activity),
+    emoji("art", "🎨", Activity),
+    emoji("circus_tent", "🎪", Activity),
+    emoji("woman_juggling", "🤹‍♀️", Activity),
+    emoji("man_juggling", "🤹‍♂️", Activity),
+    emoji("microphone", "🎤", Activity),
+    emoji("headphones", "🎧", Activity),
+    emoji("musical_score", "🎼", Activity),
+    emoji("musical_keyboard", "🎹", Activity),
+    emoji("drum", "🥁", Activity),
+    emoji("saxophone", "🎷", Activity),
+    emoji("trumpet", "🎺", Activity),
+    emoji("guitar", "🎸", Activity),
+    emoji("violin", "🎻", Activity),
+    emoji("clapper", "🎬", Activity),
+    emoji("video_game", "🎮", Activity),
+    emoji("space_invader", "👾", Activity),
+    emoji("dart", "🎯", Activity),
+    emoji("game_die", "🎲", Activity),
+    emoji("chess_pawn", "♟️", Activity),
+    emoji("slot_machine", "🎰", Activity),
+    emoji("jigsaw", "🧩", Activity),
+    emoji("bowling", "🎳", Activity),
+    emoji("red_car", "🚗", Travel),
+    emoji("taxi", "🚕", Travel),
+    emoji("blue_car", "🚙", Travel),
+    emoji("bus", "🚌", Travel),
+    emoji("trolleybus", "🚎", Travel),
+    emoji("racing_car", "🏎️", Travel),
+    emoji("police_car", "🚓", Travel),
+    emoji("ambulance", "🚑", Travel),
+    emoji("fire_engine", "🚒", Travel),
+    emoji("minibus", "🚐", Travel),
+    emoji("truck", "🚚", Travel),
+    emoji("articulated_lorry", "🚛", Travel),
+    emoji("tractor", "🚜", Travel),
+    emoji("kick_scooter", "🛴", Travel),
+    emoji("motorcycle", "🏍️", Travel),
+    emoji("bike", "🚲", Travel),
+    emoji("motor_scooter", "🛵", Travel),
+    emoji("rotating_light", "🚨", Travel),
+    emoji("oncoming_police_car", "🚔", Travel),
+    emoji("oncoming_bus", "🚍", Travel),
+    emoji("oncoming_automobile", "🚘", Travel),
+    emoji("oncoming_taxi", "🚖", Travel),
+    emoji("aerial_tramway", "🚡", Travel),
+    emoji("mountain_cableway", "🚠", Travel),
+    emoji("suspension_railway", "🚟", Travel),
+    emoji("railway_car", "🚃", Travel),
+    emoji("train", "🚋", Travel),
+    emoji("monorail", "🚝", Travel),
+    emoji("bullettrain_side", "🚄", Travel),
+    emoji("bullettrain_front", "🚅", Travel),
+    emoji("light_rail", "🚈", Travel),
+    emoji("mountain_railway", "🚞", Travel),
+    emoji("steam_locomotive", "🚂", Travel),
+    emoji("train2", "🚆", Travel),
+    emoji("metro", "🚇", Travel),
+    emoji("tram", "🚊", Travel),
+    emoji("station", "🚉", Travel),
+    emoji("flying_saucer", "🛸", Travel),
+    emoji("helicopter", "🚁", Travel),
+    emoji("small_airplane", "🛩️", Travel),
+    emoji("airplane", "✈️", Travel),
+    emoji("flight_departure", "🛫", Travel),
+    emoji("flight_arrival", "🛬", Travel),
+    emoji("sailboat", "⛵", Travel),
+    emoji("motor_boat", "🛥️", Travel),
+    emoji("speedboat", "🚤", Travel),
+    emoji("ferry", "⛴️", Travel),
+    emoji("passenger_ship", "🛳️", Travel),
+    emoji("rocket", "🚀", Travel),
+    emoji("artificial_satellite", "🛰️", Travel),
+    emoji("seat", "💺", Travel),
+    emoji("canoe", "🛶", Travel),
+    emoji("anchor", "⚓", Travel),
+    emoji("construction", "🚧", Travel),
+    emoji("fuelpump", "⛽", Travel),
+    emoji("busstop", "🚏", Travel),
+    emoji("vertical_traffic_light", "🚦", Travel),
+    emoji("traffic_light", "🚥", Travel),
+    emoji("checkered_flag", "🏁", Travel),
+    emoji("ship", "🚢", Travel),
+    emoji("ferris_wheel", "🎡", Travel),
+    emoji("roller_coaster", "🎢", Travel),
+    emoji("carousel_horse", "🎠", Travel),
+    emoji("building_construction", "🏗️", Travel),
+    emoji("foggy", "🌁", Travel),
+    emoji("tokyo_tower", "🗼", Travel),
+    emoji("factory", "🏭", Travel),
+    emoji("fountain", "⛲", Travel),
+    emoji("rice_scene", "🎑", Travel),
+    emoji("mountain", "⛰️", Travel),
+    emoji("mountain_snow", "🏔️", Travel),
+    emoji("mount_fuji", "🗻", Travel),
+    emoji("volcano", "🌋", Travel),
+    emoji("japan", "🗾", Travel),
+    emoji("camping", "🏕️", Travel),
+    emoji("tent", "⛺", Travel),
+    emoji("national_park", "🏞️", Travel),
+    emoji("motorway", "🛣️", Travel),
+    emoji("railway_track", "🛤️", Travel),
+    emoji("sunrise", "🌅", Travel),
+    emoji("sunrise_over_mountains", "🌄", Travel),
+    emoji("desert", "🏜️", Travel),
+    emoji("beach_umbrella", "🏖️", Travel),
+    emoji("desert_island", "🏝️", Travel),
+    emoji("city_sunrise", "🌇", Travel),
+    emoji("city_sunset", "🌆", Travel),
+    emoji("cityscape", "🏙️", Travel),
+    emoji("night_with_stars", "🌃", Travel),
+    emoji("bridge_at_night", "🌉", Travel),
+    emoji("milky_way", "🌌", Travel),
+    emoji("stars", "🌠", Travel),
+    emoji("sparkler", "🎇", Travel),
+    emoji("fireworks", "🎆", Travel),
+    emoji("rainbow", "🌈", Travel),
+    emoji("houses", "🏘️", Travel),
+    emoji("european_castle", "🏰", Travel),
+    emoji("japanese_castle", "🏯", Travel),
+    emoji("stadium", "🏟️", Travel),
+    emoji("statue_of_liberty", "🗽", Travel),
+    emoji("house", "🏠", Travel),
+    emoji("house_with_garden", "🏡", Travel),
+    emoji("derelict_house", "🏚️", Travel),
+    emoji("office", "🏢", Travel),
+    emoji("department_store", "🏬", Travel),
+    emoji("post_office", "🏣", Travel),
+    emoji("european_post_office", "🏤", Travel),
+    emoji("hospital", "🏥", Travel),
+    emoji("bank", "🏦", Travel),
+    emoji("hotel", "🏨", Travel),
+    emoji("convenience_store", "🏪", Travel),
+    emoji("school", "🏫", Travel),
+    emoji("love_hotel", "🏩", Travel),
+    emoji("wedding", "💒", Travel),
+    emoji("classical_building", "🏛️", Travel),
+    emoji("church", "⛪", Travel),
+    emoji("mosque", "🕌", Travel),
+    emoji("synagogue", "🕍", Travel),
+    emoji("kaaba", "🕋", Travel),
+    emoji("shinto_shrine", "⛩️", Travel),
+    emoji("watch", "⌚", Objects),
+    emoji("iphone", "📱", Objects),
+    emoji("calling", "📲", Objects),
+    emoji("computer", "💻", Objects),
+    emoji("keyboard", "⌨️", Objects),
+    emoji("desktop_computer", "🖥️", Objects),
+    emoji("printer", "🖨️", Objects),
+    emoji("computer_mouse", "🖱️", Objects),
+    emoji("trackball", "🖲️", Objects),
+    emoji("joystick", "🕹️", Objects),
+    emoji("clamp", "🗜️", Objects),
+    emoji("minidisc", "💽", Objects),
+    emoji("floppy_disk", "💾", Objects),
+    emoji("cd", "💿", Objects),
+    emoji("dvd", "📀", Objects),
+    emoji("vhs", "📼", Objects),
+    emoji("camera", "📷", Objects),
+    emoji("camera_flash", "📸", Objects),
+    emoji("video_camera", "📹", Objects),
+    emoji("movie_camera", "🎥", Objects),
+    emoji("film_projector", "📽️", Objects),
+    emoji("film_strip", "🎞️", Objects),
+    emoji("telephone_receiver", "📞", Objects),
+    emoji("phone", "☎️", Objects),
+    emoji("pager", "📟", Objects),
+    emoji("fax", "📠", Objects),
+    emoji("tv", "📺", Objects),
+    emoji("radio", "📻", Objects),
+    emoji("studio_microphone", "🎙️", Objects),
+    emoji("level_slider", "🎚️", Objects),
+    emoji("control_knobs", "🎛️", Objects),
+    emoji("compass", "🧭", Objects),
+    emoji("stopwatch", "⏱️", Objects),
+    emoji("timer_clock", "⏲️", Objects),
+    emoji("alarm_clock", "⏰", Objects),
+    emoji("mantelpiece_clock", "🕰️", Objects),
+    emoji("hourglass_flowing_sand", "⏳", Objects),
+    emoji("hourglass", "⌛", Objects),
+    emoji("satellite", "📡", Objects),
+    emoji("battery", "🔋", Objects),
+    emoji("electric_plug", "🔌", Objects),
+    emoji("bulb", "💡", Objects),
+    emoji("flashlight", "🔦", Objects),
+    emoji("candle", "🕯️", Objects),
+    emoji("fire_extinguisher", "🧯", Objects),
+    emoji("wastebasket", "🗑️", Objects),
+    emoji("oil_drum", "🛢️", Objects),
+    emoji("money_with_wings", "💸", Objects),
+    emoji("dollar", "💵", Objects),
+    emoji("yen", "💴", Objects),
+    emoji("euro", "💶", Objects),
+    emoji("pound", "💷", Objects),
+    emoji("moneybag", "💰", Objects),
+    emoji("credit_card", "💳", Objects),
+    emoji("gem", "💎", Objects),
+    emoji("balance_scale", "⚖️", Objects),
+    emoji("toolbox", "🧰", Objects),
+    emoji("wrench", "🔧", Objects),
+    emoji("hammer", "🔨", Objects),
+    emoji("hammer_and_pick", "⚒️", Objects),
+    emoji("hammer_and_wrench", "🛠️", Objects),
+    emoji("pick", "⛏️", Objects),
+    emoji("nut_and_bolt", "🔩", Objects),
+    emoji("gear", "⚙️", Objects),
+    emoji("brick", "🧱", Objects),
+    emoji("chains", "⛓️", Objects),
+    emoji("magnet", "🧲", Objects),
+    emoji("gun", "🔫", Objects),
+    emoji("bomb", "💣", Objects),
+    emoji("firecracker", "🧨", Objects),
+    emoji("hocho", "🔪", Objects),
+    emoji("dagger", "🗡️", Objects),
+    emoji("crossed_swords", "⚔️", Objects),
+    emoji("shield", "🛡️", Objects),
+    emoji("smoking", "🚬", Objects),
+    emoji("skull_and_crossbones", "☠️", Objects),
+    emoji("coffin", "⚰️", Objects),
+    emoji("funeral_urn", "⚱️", Objects),
+    emoji("amphora", "🏺", Objects),
+    emoji("crystal_ball", "🔮", Objects),
+    emoji("prayer_beads", "📿", Objects),
+    emoji("nazar_amulet", "🧿", Objects),
+    emoji("barber", "💈", Objects),
+    emoji("alembic", "⚗️", Objects),
+    emoji("telescope", "🔭", Objects),
+    emoji("microscope", "🔬", Objects),
+    emoji("hole", "🕳️", Objects),
+    emoji("pill", "💊", Objects),
+    emoji("syringe", "💉", Objects),
+    emoji("dna", "🧬", Objects),
+    emoji("microbe", "🦠", Objects),
+    emoji("petri_dish", "🧫", Objects),
+    emoji("test_tube", "🧪", Objects),
+    emoji("thermometer", "🌡️", Objects),
+    emoji("broom", "🧹", Objects),
+    emoji("basket", "🧺", Objects),
+    emoji("toilet_paper", "🧻", Objects),
+    emoji("label", "🏷️", Objects),
+    emoji("bookmark", "🔖", Objects),
+    emoji("toilet", "🚽", Objects),
+    emoji("shower", "🚿", Objects),
+    emoji("bathtub", "🛁", Objects),
+    emoji("soap", "🧼", Objects),
+    emoji("sponge", "🧽", Objects),
+    emoji("lotion_bottle", "🧴", Objects),
+    emoji("key", "🔑", Objects),
+    emoji("old_key", "🗝️", Objects),
+    emoji("couch_and_lamp", "🛋️", Objects),
+    emoji("sleeping_bed", "🛌", Objects),
+    emoji("bed", "🛏️", Objects),
+    emoji("door", "🚪", Objects),
+    emoji("bellhop_bell", "🛎️", Objects),
+    emoji("teddy_bear", "🧸", Objects),
+    emoji("framed_picture", "🖼️", Objects),
+    emoji("world_map", "🗺️", Objects),
+    emoji("parasol_on_ground", "⛱️", Objects),
+    emoji("moyai", "🗿", Objects),
+    emoji("shopping", "🛍️", Objects),
+    emoji("shopping_cart", "🛒", Objects),
+    emoji("balloon", "🎈", Objects),
+    emoji("flags", "🎏", Objects),
+    emoji("ribbon", "🎀", Objects),
+    emoji("gift", "🎁", Objects),
+    emoji("confetti_ball", "🎊", Objects),
+    emoji("tada", "🎉", Objects),
+    emoji("dolls", "🎎", Objects),
+    emoji("wind_chime", "🎐", Objects),
+    emoji("crossed_flags", "🎌", Objects),
+    emoji("izakaya_lantern", "🏮", Objects),
+    emoji("red_envelope", "🧧", Objects),
+    emoji("email", "✉️", Objects),
+    emoji("envelope_with_arrow", "📩", Objects),
+    emoji("incoming_envelope", "📨", Objects),
+    emoji("e-mail", "📧", Objects),
+    emoji("love_letter", "💌", Objects),
+    emoji("postbox", "📮", Objects),
+    emoji("mailbox_closed", "📪", Objects),
+    emoji("mailbox", "📫", Objects),
+    emoji("mailbox_with_mail", "📬", Objects),
+    emoji("mailbox_with_no_mail", "📭", Objects),
+    emoji("package", "📦", Objects),
+    emoji("postal_horn", "📯", Objects),
+    emoji("inbox_tray", "📥", Objects),
+    emoji("outbox_tray", "📤", Objects),
+    emoji("scroll", "📜", Objects),
+    emoji("page_with_curl", "📃", Objects),
+    emoji("bookmark_tabs", "📑", Objects),
+    emoji("receipt", "🧾", Objects),
+    emoji("bar_chart", "📊", Objects),
+    emoji("chart_with_upwards_trend", "📈", Objects),
+    emoji("chart_with_downwards_trend", "📉", Objects),
+    emoji("page_facing_up", "📄", Objects),
+    emoji("date", "📅", Objects),
+    emoji("calendar", "📆", Objects),
+    emoji("spiral_calendar", "🗓️", Objects),
+    emoji("card_index", "📇", Objects),
+    emoji("card_file_box", "🗃️", Objects),
+    emoji("ballot_box", "🗳️", Objects),
+    emoji("file_cabinet", "🗄️", Objects),
+    emoji("clipboard", "📋", Objects),
+    emoji("spiral_notepad", "🗒️", Objects),
+    emoji("file_folder", "📁", Objects),
+    emoji("open_file_folder", "📂", Objects),
+    emoji("card_index_dividers", "🗂️", Objects),
+    emoji("newspaper_roll", "🗞️", Objects),
+    emoji("newspaper", "📰", Objects),
+    emoji("notebook", "📓", Objects),
+    emoji("closed_book", "📕", Objects),
+    emoji("green_book", "📗", Objects),
+    emoji("blue_book", "📘", Objects),
+    emoji("orange_book", "📙", Objects),
+    emoji("notebook_with_decorative_cover", "📔", Objects),
+    emoji("ledger", "📒", Objects),
+    emoji("books", "📚", Objects),
+    emoji("open_book", "📖", Objects),
+    emoji("safety_pin", "🧷", Objects),
+    emoji("link", "🔗", Objects),
+    emoji("paperclip", "📎", Objects),
+    emoji("paperclips", "🖇️", Objects),
+    emoji("scissors", "✂️", Objects),
+    emoji("triangular_ruler", "📐", Objects),
+    emoji("straight_ruler", "📏", Objects),
+    emoji("abacus", "🧮", Objects),
+    emoji("pushpin", "📌", Objects),
+    emoji("round_pushpin", "📍", Objects),
+    emoji("triangular_flag_on_post", "🚩", Objects),
+    emoji("white_flag", "🏳️", Objects),
+    emoji("black_flag", "🏴", Objects),
+    emoji("rainbow_flag", "🏳️‍🌈", Objects),
+    emoji("closed_lock_with_key", "🔐", Objects),
+    emoji("lock", "🔒", Objects),
+    emoji("unlock", "🔓", Objects),
+    emoji("lock_with_ink_pen", "🔏", Objects),
+    emoji("pen", "🖊️", Objects),
+    emoji("fountain_pen", "🖋️", Objects),
+    emoji("black_nib", "✒️", Objects),
+    emoji("memo", "📝", Objects),
+    emoji("pencil2", "✏️", Objects),
+    emoji("crayon", "🖍️", Objects),
+    emoji("paintbrush", "🖌️", Objects),
+    emoji("mag", "🔍", Objects),
+    emoji("mag_right", "🔎", Objects),
+    emoji("heart", "❤️", Symbols),
+    emoji("orange_heart", "🧡", Symbols),
+    emoji("yellow_heart", "💛", Symbols),
+    emoji("green_heart", "💚", Symbols),
+    emoji("blue_heart", "💙", Symbols),
+    emoji("purple_heart", "💜", Symbols),
+    emoji("black_heart", "🖤", Symbols),
+    emoji("broken_heart", "💔", Symbols),
+    emoji("heavy_heart_exclamation", "❣️", Symbols),
+    emoji("two_hearts", "💕", Symbols),
+    emoji("revolving_hearts", "💞", Symbols),
+    emoji("heartbeat", "💓", Symbols),
+    emoji("heartpulse", "💗", Symbols),
+    emoji("sparkling_heart", "💖", Symbols),
+    emoji("cupid", "💘", Symbols),
+    emoji("gift_heart", "💝", Symbols),
+    emoji("heart_decoration", "💟", Symbols),
+    emoji("peace_symbol", "☮️", Symbols),
+    emoji("latin_cross", "✝️", Symbols),
+    emoji("star_and_crescent", "☪️", Symbols),
+    emoji("om", "🕉️", Symbols),
+    emoji("wheel_of_dharma", "☸️", Symbols),
+    emoji("star_of_david", "✡️", Symbols),
+    emoji("six_pointed_star", "🔯", Symbols),
+    emoji("menorah", "🕎", Symbols),
+    emoji("yin_yang", "☯️", Symbols),
+    emoji("orthodox_cross", "☦️", Symbols),
+    emoji("place_of_worship", "🛐", Symbols),
+    emoji("ophiuchus", "⛎", Symbols),
+    emoji("aries", "♈", Symbols),
+    emoji("taurus", "♉", Symbols),
+    emoji("gemini", "♊", Symbols),
+    emoji("cancer", "♋", Symbols),
+    emoji("leo", "♌", Symbols),
+    emoji("virgo", "♍", Symbols),
+    emoji("libra", "♎", Symbols),
+    emoji("scorpius", "♏", Symbols),
+    emoji("sagittarius", "♐", Symbols),
+    emoji("capricorn", "♑", Symbols),
+    emoji("aquarius", "♒", Symbols),
+    emoji("pisces", "♓", Symbols),
+    emoji("id", "🆔", Symbols),
+    emoji("atom_symbol", "⚛️", Symbols),
+    emoji("u7a7a", "🈳", Symbols),
+    emoji("u5272", "🈹", Symbols),
+    emoji("radioactive", "☢️", Symbols),
+    emoji("biohazard", "☣️", Symbols),
+    emoji("mobile_phone_off", "📴", Symbols),
+    emoji("vibration_mode", "📳", Symbols),
+    emoji("u6709", "🈶", Symbols),
+    emoji("u7121", "🈚", Symbols),
+    emoji("u7533", "🈸", Symbols),
+    emoji("u55b6", "🈺", Symbols),
+    emoji("u6708", "🈷️", Symbols),
+    emoji("eight_pointed_black_star", "✴️", Symbols),
+    emoji("vs", "🆚", Symbols),
+    emoji("accept", "🉑", Symbols),
+    emoji("white_flower", "💮", Symbols),
+    emoji("ideograph_advantage", "🉐", Symbols),
+    emoji("secret", "㊙️", Symbols),
+    emoji("congratulations", "㊗️", Symbols),
+    emoji("u5408", "🈴", Symbols),
+    emoji("u6e80", "🈵", Symbols),
+    emoji("u7981", "🈲", Symbols),
+    emoji("a", "🅰️", Symbols),
+    emoji("b", "🅱️", Symbols),
+    emoji("ab", "🆎", Symbols),
+    emoji("cl", "🆑", Symbols),
+    emoji("o2", "🅾️", Symbols),
+    emoji("sos", "🆘", Symbols),
+    emoji("no_entry", "⛔", Symbols),
+    emoji("name_badge", "📛", Symbols),
+    emoji("no_entry_sign", "🚫", Symbols),
+    emoji("x", "❌", Symbols),
+    emoji("o", "⭕", Symbols),
+    emoji("stop_sign", "🛑", Symbols),
+    emoji("anger", "💢", Symbols),
+    emoji("hotsprings", "♨️", Symbols),
+    emoji("no_pedestrians", "🚷", Symbols),
+    emoji("do_not_litter", "🚯", Symbols),
+    emoji("no_bicycles", "🚳", Symbols),
+    emoji("non-potable_water", "🚱", Symbols),
+    emoji("underage", "🔞", Symbols),
+    emoji("no_mobile_phones", "📵", Symbols),
+    emoji("exclamation", "❗", Symbols),
+    emoji("grey_exclamation", "❕", Symbols),
+    emoji("question", "❓", Symbols),
+    emoji("grey_question", "❔", Symbols),
+    emoji("bangbang", "‼️", Symbols),
+    emoji("interrobang", "⁉️", Symbols),
+    emoji("100", "💯", Symbols),
+    emoji("low_brightness", "🔅", Symbols),
+    emoji("high_brightness", "🔆", Symbols),
+    emoji("trident", "🔱", Symbols),
+    emoji("fleur_de_lis", "⚜️", Symbols),
+    emoji("part_alternation_mark", "〽️", Symbols),
+    emoji("warning", "⚠️", Symbols),
+    emoji("children_crossing", "🚸", Symbols),
+    emoji("beginner", "🔰", Symbols),
+    emoji("recycle", "♻️", Symbols),
+    emoji("u6307", "🈯", Symbols),
+    emoji("chart", "💹", Symbols),
+    emoji("sparkle", "❇️", Symbols),
+    emoji("eight_spoked_asterisk", "✳️", Symbols),
+    emoji("negative_squared_cross_mark", "❎", Symbols),
+    emoji("white_check_mark", "✅", Symbols),
+    emoji("diamond_shape_with_a_dot_inside", "💠", Symbols),
+    emoji("cyclone", "🌀", Symbols),
+    emoji("loop", "➿", Symbols),
+    emoji("globe_with_meridians", "🌐", Symbols),
+    emoji("m", "Ⓜ️", Symbols),
+    emoji("atm", "🏧", Symbols),
+    emoji("sa", "🈂️", Symbols),
+    emoji("passport_control", "🛂", Symbols),
+    emoji("customs", "🛃", Symbols),
+    emoji("baggage_claim", "🛄", Symbols),
+    emoji("left_luggage", "🛅", Symbols),
+    emoji("wheelchair", "♿", Symbols),
+    emoji("no_smoking", "🚭", Symbols),
+    emoji("wc", "🚾", Symbols),
+    emoji("parking", "🅿️", Symbols),
+    emoji("potable_water", "🚰", Symbols),
+    emoji("mens", "🚹", Symbols),
+    emoji("womens", "🚺", Symbols),
+    emoji("baby_symbol", "🚼", Symbols),
+    emoji("restroom", "🚻", Symbols),
+    emoji("put_litter_in_its_place", "🚮", Symbols),
+    emoji("cinema", "🎦", Symbols),
+    emoji("signal_strength", "📶", Symbols),
+    emoji("koko", "🈁", Symbols),
+    emoji("ng", "🆖", Symbols),
+    emoji("ok", "🆗", Symbols),
+    emoji("up", "🆙", Symbols),
+    emoji("cool", "🆒", Symbols),
+    emoji("new", "🆕", Symbols),
+    emoji("free", "🆓", Symbols),
+    emoji("zero", "0️⃣", Symbols),
+    emoji("one", "1️⃣", Symbols),
+    emoji("two", "2️⃣", Symbols),
+    emoji("three", "3️⃣", Symbols),
+    emoji("four", "4️⃣", Symbols),
+    emoji("five", "5️⃣", Symbols),
+    emoji("six", "6️⃣", Symbols),
+    emoji("seven", "7️⃣", Symbols),
+    emoji("eight", "8️⃣", Symbols),
+    emoji("nine", "9️⃣", Symbols),
+    emoji("keycap_ten", "🔟", Symbols),
+    emoji("asterisk", "*️⃣", Symbols),
+    emoji("1234", "🔢", Symbols),
+    emoji("eject_button", "⏏️", Symbols),
+    emoji("arrow_forward", "▶️", Symbols),
+    emoji("pause_button", "⏸️", Symbols),
+    emoji("next_track_button", "⏭️", Symbols),
+    emoji("stop_button", "⏹️", Symbols),
+    emoji("record_button", "⏺️", Symbols),
+    emoji("play_or_pause_button", "⏯️", Symbols),
+    emoji("previous_track_button", "⏮️", Symbols),
+    emoji("fast_forward", "⏩", Symbols),
+    emoji("rewind", "⏪", Symbols),
+    emoji("twisted_rightwards_arrows", "🔀", Symbols),
+    emoji("repeat", "🔁", Symbols),
+    emoji("repeat_one", "🔂", Symbols),
+    emoji("arrow_backward", "◀️", Symbols),
+    emoji("arrow_up_small", "🔼", Symbols),
+    emoji("arrow_down_small", "🔽", Symbols),
+    emoji("arrow_double_up", "⏫", Symbols),
+    emoji("arrow_double_down", "⏬", Symbols),
+    emoji("arrow_right", "➡️", Symbols),
+    emoji("arrow_left", "⬅️", Symbols),
+    emoji("arrow_up", "⬆️", Symbols),
+    emoji("arrow_down", "⬇️", Symbols),
+    emoji("arrow_upper_right", "↗️", Symbols),
+    emoji("arrow_lower_right", "↘️", Symbols),
+    emoji("arrow_lower_left", "↙️", Symbols),
+    emoji("arrow_upper_left", "↖️", Symbols),
+    emoji("arrow_up_down", "↕️", Symbols),
+    emoji("left_right_arrow", "↔️", Symbols),
+    emoji("arrows_counterclockwise", "🔄", Symbols),
+    emoji("arrow_right_hook", "↪️", Symbols),
+    emoji("leftwards_arrow_with_hook", "↩️", Symbols),
+    emoji("arrow_heading_up", "⤴️", Symbols),
+    emoji("arrow_heading_down", "⤵️", Symbols),
+    emoji("hash", "#️⃣", Symbols),
+    emoji("information_source", "ℹ️", Symbols),
+    emoji("abc", "🔤", Symbols),
+    emoji("abcd", "🔡", Symbols),
+    emoji("capital_abcd", "🔠", Symbols),
+    emoji("symbols", "🔣", Symbols),
+    emoji("musical_note", "🎵", Symbols),
+    emoji("notes", "🎶", Symbols),
+    emoji("wavy_dash", "〰️", Symbols),
+    emoji("curly_loop", "➰", Symbols),
+    emoji("heavy_check_mark", "✔️", Symbols),
+    emoji("arrows_clockwise", "🔃", Symbols),
+    emoji("heavy_plus_sign", "➕", Symbols),
+    emoji("heavy_minus_sign", "➖", Symbols),
+    emoji("heavy_division_sign", "➗", Symbols),
+    emoji("heavy_multiplication_x", "✖️", Symbols),
+    emoji("infinity", "♾️", Symbols),
+    emoji("heavy_dollar_sign", "💲", Symbols),
+    emoji("currency_exchange", "💱", Symbols),
+    emoji("copyright", "©️", Symbols),
+    emoji("registered", "®️", Symbols),
+    emoji("tm", "™️", Symbols),
+    emoji("end", "🔚", Symbols),
+    emoji("back", "🔙", Symbols),
+    emoji("on", "🔛", Symbols),
+    emoji("top", "🔝", Symbols),
+    emoji("soon", "🔜", Symbols),
+    emoji("ballot_box_with_check", "☑️", Symbols),
+    emoji("radio_button", "🔘", Symbols),
+    emoji("white_circle", "⚪", Symbols),
+    emoji("black_circle", "⚫", Symbols),
+    emoji("red_circle", "🔴", Symbols),
+    emoji("large_blue_circle", "🔵", Symbols),
+    emoji("small_orange_diamond", "🔸", Symbols),
+    emoji("small_blue_diamond", "🔹", Symbols),
+    emoji("large_orange_diamond", "🔶", Symbols),
+    emoji("large_blue_diamond", "🔷", Symbols),
+    emoji("small_red_triangle", "🔺", Symbols),
+    emoji("black_small_square", "▪️", Symbols),
+    emoji("white_small_square", "▫️", Symbols),
+    emoji("black_large_square", "⬛", Symbols),
+    emoji("white_large_square", "⬜", Symbols),
+    emoji("small_red_triangle_down", "🔻", Symbols),
+    emoji("black_medium_square", "◼️", Symbols),
+    emoji("white_medium_square", "◻️", Symbols),
+    emoji("black_medium_small_square", "◾", Symbols),
+    emoji("white_medium_small_square", "◽", Symbols),
+    emoji("black_square_button", "🔲", Symbols),
+    emoji("white_square_button", "🔳", Symbols),
+    emoji("speaker", "🔈", Symbols),
+    emoji("sound", "🔉", Symbols),
+    emoji("loud_sound", "🔊", Symbols),
+    emoji("mute", "🔇", Symbols),
+    emoji("mega", "📣", Symbols),
+    emoji("loudspeaker", "📢", Symbols),
+    emoji("bell", "🔔", Symbols),
+    emoji("no_bell", "🔕", Symbols),
+    emoji("black_joker", "🃏", Symbols),
+    emoji("mahjong", "🀄", Symbols),
+    emoji("spades", "♠️", Symbols),
+    emoji("clubs", "♣️", Symbols),
+    emoji("hearts", "♥️", Symbols),
+    emoji("diamonds", "♦️", Symbols),
+    emoji("flower_playing_cards", "🎴", Symbols),
+    emoji("thought_balloon", "💭", Symbols),
+    emoji("right_anger_bubble", "🗯️", Symbols),
+    emoji("speech_balloon", "💬", Symbols),
+    emoji("left_speech_bubble", "🗨️", Symbols),
+    emoji("clock1", "🕐", Symbols),
+    emoji("clock2", "🕑", Symbols),
+    emoji("clock3", "🕒", Symbols),
+    emoji("clock4", "🕓", Symbols),
+    emoji("clock5", "🕔", Symbols),
+    emoji("clock6", "🕕", Symbols),
+    emoji("clock7", "🕖", Symbols),
+    emoji("clock8", "🕗", Symbols),
+    emoji("clock9", "🕘", Symbols),
+    emoji("clock10", "🕙", Symbols),
+    emoji("clock11", "🕚", Symbols),
+    emoji("clock12", "🕛", Symbols),
+    emoji("clock130", "🕜", Symbols),
+    emoji("clock230", "🕝", Symbols),
+    emoji("clock330", "🕞", Symbols),
+    emoji("clock430", "🕟", Symbols),
+    emoji("clock530", "🕠", Symbols),
+    emoji("clock630", "🕡", Symbols),
+    emoji("clock730", "🕢", Symbols),
+    emoji("clock830", "🕣", Symbols),
+    emoji("clock930", "🕤", Symbols),
+    emoji("clock1030", "🕥", Symbols),
+    emoji("clock1130", "🕦", Symbols),
+    emoji("clock1230", "🕧", Symbols),
+    emoji("afghanistan", "🇦🇫", Flags),
+    emoji("aland_islands", "🇦🇽", Flags),
+    emoji("albania", "🇦🇱", Flags),
+    emoji("algeria", "🇩🇿", Flags),
+    emoji("american_samoa", "🇦🇸", Flags),
+    emoji("andorra", "🇦🇩", Flags),
+    emoji("angola", "🇦🇴", Flags),
+    emoji("anguilla", "🇦🇮", Flags),
+    emoji("antarctica", "🇦🇶", Flags),
+    emoji("antigua_barbuda", "🇦🇬", Flags),
+    emoji("argentina", "🇦🇷", Flags),
+    emoji("armenia", "🇦🇲", Flags),
+    emoji("aruba", "🇦🇼", Flags),
+    emoji("ascension_island", "🇦🇨", Flags),
+    emoji("australia", "🇦🇺", Flags),
+    emoji("austria", "🇦🇹", Flags),
+    emoji("azerbaijan", "🇦🇿", Flags),
+    emoji("bahamas", "🇧🇸", Flags),
+    emoji("bahrain", "🇧🇭", Flags),
+    emoji("bangladesh", "🇧🇩", Flags),
+    emoji("barbados", "🇧🇧", Flags),
+    emoji("belarus", "🇧🇾", Flags),
+    emoji("belgium", "🇧🇪", Flags),
+    emoji("belize", "🇧🇿", Flags),
+    emoji("benin", "🇧🇯", Flags),
+    emoji("bermuda", "🇧🇲", Flags),
+    emoji("bhutan", "🇧🇹", Flags),
+    emoji("bolivia", "🇧🇴", Flags),
+    emoji("caribbean_netherlands", "🇧🇶", Flags),
+    emoji("bosnia_herzegovina", "🇧🇦", Flags),
+    emoji("botswana", "🇧🇼", Flags),
+    emoji("bouvet_island", "🇧🇻", Flags),
+    emoji("brazil", "🇧🇷", Flags),
+    emoji("british_indian_ocean_territory", "🇮🇴", Flags),
+    emoji("british_virgin_islands", "🇻🇬", Flags),
+    emoji("brunei", "🇧🇳", Flags),
+    emoji("bulgaria", "🇧🇬", Flags),
+    emoji("burkina_faso", "🇧🇫", Flags),
+    emoji("burundi", "🇧🇮", Flags),
+    emoji("cape_verde", "🇨🇻", Flags),
+    emoji("cambodia", "🇰🇭", Flags),
+    emoji("cameroon", "🇨🇲", Flags),
+    emoji("canada", "🇨🇦", Flags),
+    emoji("canary_islands", "🇮🇨", Flags),
+    emoji("cayman_islands", "🇰🇾", Flags),
+    emoji("central_african_republic", "🇨🇫", Flags),
+    emoji("ceuta_melilla", "🇪🇦", Flags),
+    emoji("chad", "🇹🇩", Flags),
+    emoji("chile", "🇨🇱", Flags),
+    emoji("cn", "🇨🇳", Flags),
+    emoji("christmas_island", "🇨🇽", Flags),
+    emoji("clipperton_island", "🇨🇵", Flags),
+    emoji("cocos_islands", "🇨🇨", Flags),
+    emoji("colombia", "🇨🇴", Flags),
+    emoji("comoros", "🇰🇲", Flags),
+    emoji("congo_brazzaville", "🇨🇬", Flags),
+    emoji("congo_kinshasa", "🇨🇩", Flags),
+    emoji("cook_islands", "🇨🇰", Flags),
+    emoji("costa_rica", "🇨🇷", Flags),
+    emoji("croatia", "🇭🇷", Flags),
+    emoji("cuba", "🇨🇺", Flags),
+    emoji("curacao", "🇨🇼", Flags),
+    emoji("cyprus", "🇨🇾", Flags),
+    emoji("czech_republic", "🇨🇿", Flags),
+    emoji("denmark", "🇩🇰", Flags),
+    emoji("diego_garcia", "🇩🇬", Flags),
+    emoji("djibouti", "🇩🇯", Flags),
+    emoji("dominica", "🇩🇲", Flags),
+    emoji("dominican_republic", "🇩🇴", Flags),
+    emoji("ecuador", "🇪🇨", Flags),
+    emoji("egypt", "🇪🇬", Flags),
+    emoji("el_salvador", "🇸🇻", Flags),
+    emoji("equatorial_guinea", "🇬🇶", Flags),
+    emoji("eritrea", "🇪🇷", Flags),
+    emoji("estonia", "🇪🇪", Flags),
+    emoji("ethiopia", "🇪🇹", Flags),
+    emoji("eu", "🇪🇺", Flags),
+    emoji("falkland_islands", "🇫🇰", Flags),
+    emoji("faroe_islands", "🇫🇴", Flags),
+    emoji("fiji", "🇫🇯", Flags),
+    emoji("finland", "🇫🇮", Flags),
+    emoji("fr", "🇫🇷", Flags),
+    emoji("french_guiana", "🇬🇫", Flags),
+    emoji("french_polynesia", "🇵🇫", Flags),
+    emoji("french_southern_territories", "🇹🇫", Flags),
+    emoji("gabon", "🇬🇦", Flags),
+    emoji("gambia", "🇬🇲", Flags),
+    emoji("georgia", "🇬🇪", Flags),
+    emoji("de", "🇩🇪", Flags),
+    emoji("ghana", "🇬🇭", Flags),
+    emoji("gibraltar", "🇬🇮", Flags),
+    emoji("greece", "🇬🇷", Flags),
+    emoji("greenland", "🇬🇱", Flags),
+    emoji("grenada", "🇬🇩", Flags),
+    emoji("guadeloupe", "🇬🇵", Flags),
+    emoji("guam", "🇬🇺", Flags),
+    emoji("guatemala", "🇬🇹", Flags),
+    emoji("guernsey", "🇬🇬", Flags),
+    emoji("guinea", "🇬🇳", Flags),
+    emoji("guinea_bissau", "🇬🇼", Flags),
+    emoji("guyana", "🇬🇾", Flags),
+    emoji("haiti", "🇭🇹", Flags),
+    emoji("heard_mcdonald_islands", "🇭🇲", Flags),
+    emoji("honduras", "🇭🇳", Flags),
+    emoji("hong_kong", "🇭🇰", Flags),
+    emoji("hungary", "🇭🇺", Flags),
+    emoji("iceland", "🇮🇸", Flags),
+    emoji("india", "🇮🇳", Flags),
+    emoji("indonesia", "🇮🇩", Flags),
+    emoji("iran", "🇮🇷", Flags),
+    emoji("iraq", "🇮🇶", Flags),
+    emoji("ireland", "🇮🇪", Flags),
+    emoji("isle_of_man", "🇮🇲", Flags),
+    emoji("israel", "🇮🇱", Flags),
+    emoji("it", "🇮🇹", Flags),
+    emoji("cote_divoire", "🇨🇮", Flags),
+    emoji("jamaica", "🇯🇲", Flags),
+    emoji("jp", "🇯🇵", Flags),
+    emoji("jersey", "🇯🇪", Flags),
+    emoji("jordan", "🇯🇴", Flags),
+    emoji("kazakhstan", "🇰🇿", Flags),
+    emoji("kenya", "🇰🇪", Flags),
+    emoji("kiribati", "🇰🇮", Flags),
+    emoji("kosovo", "🇽🇰", Flags),
+    emoji("kuwait", "🇰🇼", Flags),
+    emoji("kyrgyzstan", "🇰🇬", Flags),
+    emoji("laos", "🇱🇦", Flags),
+    emoji("latvia", "🇱🇻", Flags),
+    emoji("lebanon", "🇱🇧", Flags),
+    emoji("lesotho", "🇱🇸", Flags),
+    emoji("liberia", "🇱🇷", Flags),
+    emoji("libya", "🇱🇾", Flags),
+    emoji("liechtenstein", "🇱🇮", Flags),
+    emoji("lithuania", "🇱🇹", Flags),
+    emoji("luxembourg", "🇱🇺", Flags),
+    emoji("macau", "🇲🇴", Flags),
+    emoji("macedonia", "🇲🇰", Flags),
+    emoji("madagascar", "🇲🇬", Flags),
+    emoji("malawi", "🇲🇼", Flags),
+    emoji("malaysia", "🇲🇾", Flags),
+    emoji("maldives", "🇲🇻", Flags),
+    emoji("mali", "🇲🇱", Flags),
+    emoji("malta", "🇲🇹", Flags),
+    emoji("marshall_islands", "🇲🇭", Flags),
+    emoji("martinique", "🇲🇶", Flags),
+    emoji("mauritania", "🇲🇷", Flags),
+    emoji("mauritius", "🇲🇺", Flags),
+    emoji("mayotte", "🇾🇹", Flags),
+    emoji("mexico", "🇲🇽", Flags),
+    emoji("micronesia", "🇫🇲", Flags),
+    emoji("moldova", "🇲🇩", Flags),
+    emoji("monaco", "🇲🇨", Flags),
+    emoji("mongolia", "🇲🇳", Flags),
+    emoji("montenegro", "🇲🇪", Flags),
+    emoji("montserrat", "🇲🇸", Flags),
+    emoji("morocco", "🇲🇦", Flags),
+    emoji("mozambique", "🇲🇿", Flags),
+    emoji("myanmar", "🇲🇲", Flags),
+    emoji("namibia", "🇳🇦", Flags),
+    emoji("nauru", "🇳🇷", Flags),
+    emoji("nepal", "🇳🇵", Flags),
+    emoji("netherlands", "🇳🇱", Flags),
+    emoji("new_caledonia", "🇳🇨", Flags),
+    emoji("new_zealand", "🇳🇿", Flags),
+    emoji("nicaragua", "🇳🇮", Flags),
+    emoji("niger", "🇳🇪", Flags),
+    emoji("nigeria", "🇳🇬", Flags),
+    emoji("niue", "🇳🇺", Flags),
+    emoji("norfolk_island", "🇳🇫", Flags),
+    emoji("northern_mariana_islands", "🇲🇵", Flags),
+    emoji("north_korea", "🇰🇵", Flags),
+    emoji("norway", "🇳🇴", Flags),
+    emoji("oman", "🇴🇲", Flags),
+    emoji("pakistan", "🇵🇰", Flags),
+    emoji("palau", "🇵🇼", Flags),
+    emoji("palestinian_territories", "🇵🇸", Flags),
+    emoji("panama", "🇵🇦", Flags),
+    emoji("papua_new_guinea", "🇵🇬", Flags),
+    emoji("paraguay", "🇵🇾", Flags),
+    emoji("peru", "🇵🇪", Flags),
+    emoji("philippines", "🇵🇭", Flags),
+    emoji("pitcairn_islands", "🇵🇳", Flags),
+    emoji("poland", "🇵🇱", Flags),
+    emoji("portugal", "🇵🇹", Flags),
+    emoji("puerto_rico", "🇵🇷", Flags),
+    emoji("qatar", "🇶🇦", Flags),
+    emoji("reunion", "🇷🇪", Flags),
+    emoji("romania", "🇷🇴", Flags),
+    emoji("ru", "🇷🇺", Flags),
+    emoji("rwanda", "🇷🇼", Flags),
+    emoji("st_barthelemy", "🇧🇱", Flags),
+    emoji("st_helena", "🇸🇭", Flags),
+    emoji("st_kitts_nevis", "🇰🇳", Flags),
+    emoji("st_lucia", "🇱🇨", Flags),
+    emoji("st_martin", "🇲🇫", Flags),
+    emoji("st_pierre_miquelon", "🇵🇲", Flags),
+    emoji("st_vincent_grenadines", "🇻🇨", Flags),
+    emoji("samoa", "🇼🇸", Flags),
+    emoji("san_marino", "🇸🇲", Flags),
+    emoji("sao_tome_principe", "🇸🇹", Flags),
+    emoji("saudi_arabia", "🇸🇦", Flags),
+    emoji("senegal", "🇸🇳", Flags),
+    emoji("serbia", "🇷🇸", Flags),
+    emoji("seychelles", "🇸🇨", Flags),
+    emoji("sierra_leone", "🇸🇱", Flags),
+    emoji("singapore", "🇸🇬", Flags),
+    emoji("sint_maarten", "🇸🇽", Flags),
+    emoji("slovakia", "🇸🇰", Flags),
+    emoji("slovenia", "🇸🇮", Flags),
+    emoji("solomon_islands", "🇸🇧", Flags),
+    emoji("somalia", "🇸🇴", Flags),
+    emoji("south_africa", "🇿🇦", Flags),
+    emoji("south_georgia_south_sandwich_islands", "🇬🇸", Flags),
+    emoji("kr", "🇰🇷", Flags),
+    emoji("south_sudan", "🇸🇸", Flags),
+    emoji("es", "🇪🇸", Flags),
+    emoji("sri_lanka", "🇱🇰", Flags),
+    emoji("sudan", "🇸🇩", Flags),
+    emoji("suriname", "🇸🇷", Flags),
+    emoji("svalbard_jan_mayen", "🇸🇯", Flags),
+    emoji("swaziland", "🇸🇿", Flags),
+    emoji("sweden", "🇸🇪", Flags),
+    emoji("switzerland", "🇨🇭", Flags),
+    emoji("syria", "🇸🇾", Flags),
+    emoji("taiwan", "🇹🇼", Flags),
+    emoji("tajikistan", "🇹🇯", Flags),
+    emoji("tanzania", "🇹🇿", Flags),
+    emoji("thailand", "🇹🇭", Flags),
+    emoji("timor_leste", "🇹🇱", Flags),
+    emoji("togo", "🇹🇬", Flags),
+    emoji("tokelau", "🇹🇰", Flags),
+    emoji("tonga", "🇹🇴", Flags),
+    emoji("trinidad_tobago", "🇹🇹", Flags),
+    emoji("tristan_da_cunha", "🇹🇦", Flags),
+    emoji("tunisia", "🇹🇳", Flags),
+    emoji("tr", "🇹🇷", Flags),
+    emoji("turkmenistan", "🇹🇲", Flags),
+    emoji("turks_caicos_islands", "🇹🇨", Flags),
+    emoji("tuvalu", "🇹🇻", Flags),
+    emoji("uganda", "🇺🇬", Flags),
+    emoji("ukraine", "🇺🇦", Flags),
+    emoji("united_arab_emirates", "🇦🇪", Flags),
+    emoji("uk", "🇬🇧", Flags),
+    emoji("england", "🏴󠁧󠁢󠁥󠁮󠁧󠁿", Flags),
+    emoji("scotland", "🏴󠁧󠁢󠁳󠁣󠁴󠁿", Flags),
+    emoji("wales", "🏴󠁧󠁢󠁷󠁬󠁳󠁿", Flags),
+    emoji("us", "🇺🇸", Flags),
+    emoji("us_outlying_islands", "🇺🇲", Flags),
+    emoji("us_virgin_islands", "🇻🇮", Flags),
+    emoji("uruguay", "🇺🇾", Flags),
+    emoji("uzbekistan", "🇺🇿", Flags),
+    emoji("vanuatu", "🇻🇺", Flags),
+    emoji("vatican_city", "🇻🇦", Flags),
+    emoji("venezuela", "🇻🇪", Flags),
+    emoji("vietnam", "🇻🇳", Flags),
+    emoji("wallis_futuna", "🇼🇫", Flags),
+    emoji("western_sahara", "🇪🇭", Flags),
+    emoji("yemen", "🇾🇪", Flags),
+    emoji("zambia", "🇿🇲", Flags),
+    emoji("zimbabwe", "🇿🇼", Flags),
+    emoji("united_nations", "🇺🇳", Flags),
+    emoji("pirate_flag", "🏴‍☠️", Flags),
 ];
@@ -0,0 +1,101 @@
+use druid::{theme, Color, Env, FontDescriptor, FontFamily, Key};
+
+/// The outline of the hovered tile and the chosen skin tone.
+pub const HOVER_STROKE: Key<Color> = Key::new("emojay.hover-stroke");
+
+/// The outline of the selected tile and the flash of a pressed or copied one.
+pub const HIGHLIGHT: Key<Color> = Key::new("emojay.highlight");
+
+/// Accent presets that can be picked by name in place of the palette's, as the
+/// highlight and hover colors.
+const PRESETS: &[(&str, Color, Color)] = &[
+    ("muted", Color::rgb8(0x7a, 0x7f, 0x88), Color::rgb8(0x9a, 0x9f, 0xa8)),
+    ("mint", Color::rgb8(0x2f, 0xa5, 0x7c), Color::rgb8(0x8f, 0xd9, 0xbd)),
+    ("rose", Color::rgb8(0xd1, 0x4d, 0x72), Color::rgb8(0xf0, 0xa6, 0xba)),
+];
+
+/// The highlight colors chosen over the palette's own.
+#[derive(Clone, Debug, Default)]
+pub struct Accents {
+    pub highlight: Option<Color>,
+    pub hover: Option<Color>,
+}
+
+impl Accents {
+    /// Starts from the `preset` accents if there are any by that name, then takes the
+    /// `highlight` and `hover` colors given as hex. Anything that can't be read is
+    /// reported and left to the palette.
+    pub fn new(
+        preset: Option<&str>,
+        highlight: Option<&str>,
+        hover: Option<&str>,
+    ) -> Self {
+        let mut accents = Accents::default();
+        if let Some(preset) = preset {
+            match PRESETS.iter().find(|(name, ..)| *name == preset) {
+                Some((_, highlight, hover)) => {
+                    accents.highlight = Some(highlight.clone());
+                    accents.hover = Some(hover.clone());
+                }
+                None => eprintln!("emojay: no accent preset named `{}`", preset),
+            }
+        }
+        let parse = |hex: &str| {
+            Color::from_hex_str(hex)
+                .map_err(|err| eprintln!("emojay: ignoring color `{}`, {}", hex, err))
+                .ok()
+        };
+        if let Some(color) = highlight.and_then(parse) {
+            accents.highlight = Some(color);
+        }
+        if let Some(color) = hover.and_then(parse) {
+            accents.hover = Some(color);
+        }
+        accents
+    }
+
+    /// Overrides the palette's highlights, so this goes after `apply`.
+    pub fn apply(&self, env: &mut Env) {
+        if let Some(color) = &self.highlight {
+            env.set(HIGHLIGHT, color.clone());
+        }
+        if let Some(color) = &self.hover {
+            env.set(HOVER_STROKE, color.clone());
+        }
+    }
+}
+
+/// The font emojis are drawn in.
+pub const EMOJI_FONT: Key<FontDescriptor> = Key::new("emojay.emoji-font");
+
+/// Draws emojis in `family`, or in the UI font without one.
+pub fn set_emoji_font(env: &mut Env, family: Option<&str>) {
+    let font = match family {
+        Some(family) => FontDescriptor::new(FontFamily::new_unchecked(family)),
+        None => env.get(theme::UI_FONT),
+    };
+    env.set(EMOJI_FONT, font);
+}
+
+/// Sets the colors of the light or dark look, druid's own theme is the dark one.
+pub fn apply(env: &mut Env, light: bool) {
+    if !light {
+        env.set(HOVER_STROKE, Color::WHITE);
+        env.set(HIGHLIGHT, env.get(theme::PRIMARY_LIGHT));
+        return;
+    }
+    env.set(HOVER_STROKE, Color::grey8(0x30));
+    env.set(theme::WINDOW_BACKGROUND_COLOR, Color::grey8(0xf2));
+    env.set(theme::BACKGROUND_DARK, Color::grey8(0xdd));
+    env.set(theme::BACKGROUND_LIGHT, Color::WHITE);
+    env.set(theme::LABEL_COLOR, Color::grey8(0x1e));
+    env.set(theme::PLACEHOLDER_COLOR, Color::grey8(0x80));
+    env.set(theme::CURSOR_COLOR, Color::BLACK);
+    env.set(theme::PRIMARY_DARK, Color::rgb8(0xa9, 0xc9, 0xf2));
+    env.set(theme::PRIMARY_LIGHT, Color::rgb8(0x23, 0x6b, 0xd4));
+    env.set(HIGHLIGHT, Color::rgb8(0x23, 0x6b, 0xd4));
+    env.set(theme::BORDER_DARK, Color::grey8(0xb4));
+    env.set(theme::BORDER_LIGHT, Color::grey8(0x8c));
+    env.set(theme::BUTTON_DARK, Color::grey8(0xd2));
+    env.set(theme::BUTTON_LIGHT, Color::grey8(0xf5));
+}
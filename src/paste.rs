@@ -0,0 +1,19 @@
+use std::{thread, time::Duration};
+
+use enigo::{Enigo, Key, KeyboardControllable};
+
+/// Long enough for the window manager to hand focus back after the picker is minimized.
+const FOCUS_DELAY: Duration = Duration::from_millis(150);
+
+/// Presses the paste shortcut shortly, once the previously focused window has focus
+/// again.
+pub fn paste_soon() {
+    thread::spawn(|| {
+        thread::sleep(FOCUS_DELAY);
+        let modifier = if cfg!(target_os = "macos") { Key::Meta } else { Key::Control };
+        let mut enigo = Enigo::new();
+        enigo.key_down(modifier);
+        enigo.key_click(Key::Layout('v'));
+        enigo.key_up(modifier);
+    });
+}
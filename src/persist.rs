@@ -0,0 +1,284 @@
+use std::{collections::HashMap, fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// The state that is kept between runs of the picker, stored as JSON in the platform's
+/// config directory.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Saved {
+    /// Descriptions of the most recently copied emojis, newest first.
+    pub recent: Vec<String>,
+    /// Descriptions of the pinned emojis.
+    pub favorites: Vec<String>,
+    /// Descriptions of the emojis hidden from the results.
+    pub hidden: Vec<String>,
+    /// Where the window was when it was last closed.
+    pub window: Option<Geometry>,
+    /// How many times each emoji has been copied, by description.
+    pub counts: HashMap<String, u32>,
+    /// When each copy of the last week was made, in seconds since the Unix epoch.
+    pub copied_at: Vec<u64>,
+    /// The searches emojis were copied from, newest first.
+    pub searches: Vec<String>,
+    /// The preferences last changed from the picker.
+    pub toggles: Toggles,
+}
+
+/// The preferences the picker itself changes, each unset until it is. A changed one is
+/// kept over the config's, which the picker leaves as it was written, until that is
+/// edited by hand.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Toggles {
+    pub light: Option<Toggled<Option<bool>>>,
+    pub scale: Option<Toggled<f64>>,
+    pub stay_open: Option<Toggled<bool>>,
+    pub format: Option<Toggled<emojay::OutputFormat>>,
+}
+
+/// A preference as the picker left it, and as the config had it then.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct Toggled<T> {
+    pub value: T,
+    pub config: T,
+}
+
+impl<T: Copy + PartialEq> Toggled<T> {
+    /// The picker's `value` over the `config`'s, none while they are the same.
+    pub fn new(value: T, config: T) -> Option<Self> {
+        Some(Toggled { value, config }).filter(|_| value != config)
+    }
+
+    /// The value to go with, the config's if it changed since `toggled` was saved.
+    fn over(toggled: Option<Self>, config: T) -> T {
+        match toggled {
+            Some(toggled) if toggled.config == config => toggled.value,
+            _ => config,
+        }
+    }
+}
+
+/// The preferences, stored as TOML next to the saved state so they can be edited by hand.
+/// Anything left out of the file keeps its default. The picker never writes it, what is
+/// changed from the picker is saved as `Toggles` instead.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    /// How many columns the window opens with, `EMOJAY_COLUMNS` wins over it. The window
+    /// opens as wide as they need whatever width it was left at, only its height and
+    /// position are restored.
+    pub columns: usize,
+    /// The fuzzy match score an emoji has to beat to be shown.
+    pub threshold: i64,
+    /// Whether the light palette is used over the dark one, unset follows the platform.
+    pub light: Option<bool>,
+    /// The zoom of the tiles.
+    pub scale: f64,
+    /// An accent preset for the highlights, `"muted"`, `"mint"` or `"rose"`. Unset
+    /// keeps the palette's.
+    pub accent: Option<String>,
+    /// The outline of the selected tile and the flash of a copied one as `"#rrggbb"`,
+    /// over the preset's.
+    pub highlight: Option<String>,
+    /// The outline of the hovered tile as `"#rrggbb"`, over the preset's.
+    pub hover: Option<String>,
+    /// The font family emojis are drawn in, unset picks an installed color emoji font.
+    pub font: Option<String>,
+    /// Open the picker next to the mouse cursor instead of where it was left.
+    pub at_cursor: bool,
+    /// Leave the picker open after a copy, rather than hiding it or pasting into the
+    /// window under it.
+    pub stay_open: bool,
+    /// How searches are fuzzy matched, `"skim"` or `"clangd"`.
+    pub matcher: emojay::Matcher,
+    /// Also find emojis when a search is a typo or two away from a description, like
+    /// "recylce". Slower, so off unless asked for.
+    pub typos: bool,
+    /// Also search in another language, `"de"` for German. Descriptions stay English.
+    pub language: emojay::Language,
+    /// Hide emojis added after this Emoji version, like `11.0` for systems from 2018.
+    /// Unset shows them all.
+    pub max_version: Option<f64>,
+    /// The gap between the tiles of a row, in display points.
+    pub column_gap: f64,
+    /// The gap between the rows of the grid, in display points.
+    pub row_gap: f64,
+    /// The skin tone the picker starts with, `"light"`, `"medium_light"`, `"medium"`,
+    /// `"medium_dark"` or `"dark"`. The swatches still change it for a single run.
+    pub default_tone: emojay::SkinTone,
+    /// What copying puts on the clipboard, `"glyph"`, `"shortcode"`, `"html_entity"`,
+    /// `"unicode_escape"` or `"codepoints"`. Ctrl+M changes it.
+    pub format: emojay::OutputFormat,
+    /// Write each emoji's `:shortcode:` under it in the grid.
+    pub captions: bool,
+    /// Extra search terms, each naming the emojis it finds by name or by glyph, like
+    /// `ship = ["rocket"]`. Kept last as TOML writes tables after plain values.
+    pub synonyms: HashMap<String, Vec<String>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            columns: crate::DEFAULT_COLUMNS,
+            threshold: crate::DEFAULT_THRESHOLD,
+            light: None,
+            scale: 1.0,
+            accent: None,
+            highlight: None,
+            hover: None,
+            font: None,
+            at_cursor: false,
+            // Pasting needs the picker out of the way, otherwise it stays as it always
+            // has.
+            stay_open: !cfg!(feature = "paste"),
+            matcher: emojay::Matcher::default(),
+            typos: false,
+            language: emojay::Language::default(),
+            max_version: None,
+            column_gap: 1.0,
+            row_gap: 0.4,
+            captions: false,
+            default_tone: emojay::SkinTone::Default,
+            format: emojay::OutputFormat::Glyph,
+            synonyms: HashMap::new(),
+        }
+    }
+}
+
+/// The position and height of a window in display points, its width follows the columns.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct Geometry {
+    pub x: f64,
+    pub y: f64,
+    pub height: f64,
+}
+
+impl Saved {
+    /// Loads the saved state, a missing or unreadable file is treated as a fresh start.
+    pub fn load() -> Self {
+        path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> { write(path()?, &serde_json::to_string(self)?) }
+}
+
+impl Config {
+    /// Loads the preferences, a missing file means the defaults. A file that doesn't
+    /// parse is reported and ignored, and left for it to be fixed.
+    pub fn load() -> Self {
+        let toml = match config_path().and_then(fs::read_to_string) {
+            Ok(toml) => toml,
+            Err(_) => return Self::default(),
+        };
+        toml::from_str(&toml).unwrap_or_else(|err| {
+            eprintln!("emojay: ignoring config.toml, {}", err);
+            Self::default()
+        })
+    }
+
+    /// The preferences with those changed from the picker in place of the file's, but
+    /// for the ones changed in the file since.
+    pub fn with_toggles(self, toggles: &Toggles) -> Self {
+        Config {
+            light: Toggled::over(toggles.light, self.light),
+            scale: Toggled::over(toggles.scale, self.scale),
+            stay_open: Toggled::over(toggles.stay_open, self.stay_open),
+            format: Toggled::over(toggles.format, self.format),
+            ..self
+        }
+    }
+}
+
+fn write(path: PathBuf, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+fn dir() -> io::Result<PathBuf> {
+    // Tests save what they change somewhere of their own, not over the real state.
+    if cfg!(test) {
+        return Ok(
+            std::env::temp_dir().join(format!("emojay-test-{}", std::process::id()))
+        );
+    }
+    dirs::config_dir().map(|dir| dir.join("emojay")).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::NotFound, "no config directory on this platform")
+    })
+}
+
+fn path() -> io::Result<PathBuf> { Ok(dir()?.join("state.json")) }
+
+fn config_path() -> io::Result<PathBuf> { Ok(dir()?.join("config.toml")) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_round_trips() {
+        let mut config = Config {
+            columns: 7,
+            light: Some(true),
+            max_version: Some(11.0),
+            format: emojay::OutputFormat::Shortcode,
+            ..Default::default()
+        };
+        config.synonyms.insert("ship".to_owned(), vec!["rocket".to_owned()]);
+        let toml = toml::to_string(&config).unwrap();
+        let back: Config = toml::from_str(&toml).unwrap();
+        assert_eq!(format!("{:?}", back), format!("{:?}", config));
+    }
+
+    #[test]
+    fn left_out_preferences_keep_their_defaults() {
+        let config: Config = toml::from_str("columns = 3").unwrap();
+        assert_eq!(config.columns, 3);
+        assert_eq!(
+            format!("{:?}", Config { columns: 3, ..Default::default() }),
+            format!("{:?}", config)
+        );
+    }
+
+    #[test]
+    fn toggles_take_over_from_the_config() {
+        let toggles = Toggles {
+            light: Toggled::new(Some(false), None),
+            scale: Toggled::new(1.5, 1.0),
+            format: Toggled::new(
+                emojay::OutputFormat::HtmlEntity,
+                emojay::OutputFormat::Glyph,
+            ),
+            ..Default::default()
+        };
+        let config = Config { columns: 3, ..Default::default() }.with_toggles(&toggles);
+        assert_eq!((config.light, config.scale), (Some(false), 1.5));
+        assert_eq!(config.format, emojay::OutputFormat::HtmlEntity);
+        assert_eq!(config.columns, 3);
+        // Only what was changed is kept over the file's.
+        let untouched = Config::default().with_toggles(&Toggles::default());
+        assert_eq!(format!("{:?}", untouched), format!("{:?}", Config::default()));
+        assert_eq!(config.stay_open, Config::default().stay_open);
+    }
+
+    #[test]
+    fn an_edited_config_wins_over_older_toggles() {
+        let toggles = Toggles {
+            scale: Toggled::new(1.5, 1.0),
+            stay_open: Toggled::new(true, false),
+            ..Default::default()
+        };
+        let edited = Config { scale: 2.0, stay_open: false, ..Default::default() };
+        let config = edited.with_toggles(&toggles);
+        assert_eq!(config.scale, 2.0);
+        // What wasn't edited still comes from the picker.
+        assert!(config.stay_open);
+        assert_eq!(Toggled::new(1.0, 1.0), None);
+    }
+}
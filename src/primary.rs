@@ -0,0 +1,13 @@
+use arboard::{Clipboard, LinuxClipboardKind, SetExtLinux};
+
+/// The X11 primary selection, which a middle click pastes.
+pub struct Primary(Clipboard);
+
+impl Primary {
+    pub fn new() -> Result<Self, arboard::Error> { Clipboard::new().map(Primary) }
+
+    /// Makes `text` the primary selection, it is served for as long as `self` is kept.
+    pub fn set(&mut self, text: &str) -> Result<(), arboard::Error> {
+        self.0.set().clipboard(LinuxClipboardKind::Primary).text(text)
+    }
+}
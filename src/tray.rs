@@ -0,0 +1,76 @@
+use std::thread;
+
+use druid::{commands, ExtEventSink, Target};
+use tray_icon::{
+    menu::{Menu, MenuEvent, MenuItem},
+    ClickType, Icon, TrayIcon, TrayIconBuilder, TrayIconEvent,
+};
+
+use crate::{SHOW, SUMMON};
+
+/// The width and height of the tray icon in pixels.
+const ICON_SIZE: u32 = 32;
+
+/// The picker's tray icon, shown for as long as this is alive.
+pub struct Tray {
+    _icon: TrayIcon,
+}
+
+impl Tray {
+    /// Adds the tray icon, clicking it toggles the picker and its menu can show the
+    /// picker or quit.
+    pub fn new(sink: ExtEventSink) -> Result<Self, String> {
+        let show = MenuItem::new("Show", true, None);
+        let quit = MenuItem::new("Quit", true, None);
+        let menu = Menu::new();
+        menu.append_items(&[&show, &quit]).map_err(|err| err.to_string())?;
+
+        let icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip("Emoji Picker")
+            .with_icon(icon()?)
+            .build()
+            .map_err(|err| err.to_string())?;
+
+        let (show, quit) = (show.id(), quit.id());
+        let menu_sink = sink.clone();
+        thread::spawn(move || {
+            for event in MenuEvent::receiver() {
+                let sent = if event.id == show {
+                    menu_sink.submit_command(SHOW, (), Target::Auto)
+                } else if event.id == quit {
+                    menu_sink.submit_command(commands::QUIT_APP, (), Target::Global)
+                } else {
+                    Ok(())
+                };
+                if sent.is_err() {
+                    break;
+                }
+            }
+        });
+        thread::spawn(move || {
+            for event in TrayIconEvent::receiver() {
+                if event.click_type == ClickType::Left
+                    && sink.submit_command(SUMMON, (), Target::Auto).is_err()
+                {
+                    break;
+                }
+            }
+        });
+        Ok(Tray { _icon: icon })
+    }
+}
+
+/// A plain yellow disc, there is no image decoder to load a real one with.
+fn icon() -> Result<Icon, String> {
+    let radius = ICON_SIZE as f64 / 2.0;
+    let rgba = (0..ICON_SIZE * ICON_SIZE)
+        .flat_map(|pixel| {
+            let x = (pixel % ICON_SIZE) as f64 + 0.5 - radius;
+            let y = (pixel / ICON_SIZE) as f64 + 0.5 - radius;
+            let alpha = if x * x + y * y <= radius * radius { 0xff } else { 0 };
+            [0xff, 0xcc, 0x33, alpha]
+        })
+        .collect();
+    Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE).map_err(|err| err.to_string())
+}
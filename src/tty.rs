@@ -0,0 +1,26 @@
+//! Types the copied emoji into the terminal the picker was started from, as if it was
+//! typed at the shell prompt, using the `TIOCSTI` ioctl. Linux 6.2 and later turn it off
+//! unless `dev.tty.legacy_tiocsti` is set, and OpenBSD dropped it, so anything going
+//! wrong is reported and the emoji goes to the clipboard instead.
+
+use std::{fs::OpenOptions, io, os::unix::io::AsRawFd};
+
+/// Pushes `text` onto the input of the controlling terminal a byte at a time.
+pub fn insert(text: &str) -> io::Result<()> {
+    let tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+    for byte in text.bytes() {
+        // SAFETY: the fd is open for as long as `tty` is, and `TIOCSTI` only reads the
+        // one byte it is pointed at.
+        let pushed = unsafe {
+            libc::ioctl(
+                tty.as_raw_fd(),
+                libc::TIOCSTI,
+                &byte as *const u8 as *const libc::c_char,
+            )
+        };
+        if pushed != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! A Wayland compositor only offers the clipboard while the window that copied is still
+//! around, so without a clipboard manager a copied emoji is gone as soon as the picker
+//! quits. `wl-copy` from wl-clipboard forks a process that keeps serving the clipboard
+//! after that, so copies go through it when it's installed. X11, macOS and Windows keep
+//! using druid's clipboard.
+
+use std::{
+    env,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// Hands `text` to `wl-copy`, returns `false` outside a Wayland session or when it can't
+/// be run, the clipboard is then left to druid.
+pub fn copy(text: &str) -> bool {
+    if env::var_os("WAYLAND_DISPLAY").is_none() {
+        return false;
+    }
+    let mut child = match Command::new("wl-copy").stdin(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(_) => return false,
+    };
+    // Dropping stdin closes it, which is what lets `wl-copy` go serve the clipboard.
+    let written = child
+        .stdin
+        .take()
+        .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+    written && child.wait().is_ok_and(|status| status.success())
+}